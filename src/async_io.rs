@@ -0,0 +1,38 @@
+use std::io;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::WasmiumHashID;
+
+impl WasmiumHashID {
+    /// Generate a new `WasmiumHashID` by hashing everything read from an `AsyncRead`
+    /// in chunks without blocking, stamped with the current TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let data = vec![7u8; 100_000];
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// let hash_id = runtime
+    ///     .block_on(WasmiumHashID::from_async_reader(&mut data.as_slice()))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(hash_id.digest_bytes(), WasmiumHashID::new(blake3::hash(&data)).digest_bytes());
+    /// ```
+    pub async fn from_async_reader<R: AsyncRead + Unpin>(r: &mut R) -> io::Result<WasmiumHashID> {
+        let mut hasher = blake3::Hasher::new();
+        let mut chunk = [0u8; 16 * 1024];
+
+        loop {
+            let read = r.read(&mut chunk).await?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&chunk[..read]);
+        }
+
+        Ok(WasmiumHashID::from_hasher(hasher))
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{Error, WasmiumHashID, ID_LEN};
+
+/// The RFC 4648 base32 alphabet
+const BASE32_CHARS: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Decode a single RFC 4648 base32 character, accepting both lower and upper case
+const fn decode_symbol(character: u8) -> Result<u8, Error> {
+    match character {
+        b'A'..=b'Z' => Ok(character - b'A'),
+        b'a'..=b'z' => Ok(character - b'a'),
+        b'2'..=b'7' => Ok(character - b'2' + 26),
+        _ => Err(Error::InvalidEncoding),
+    }
+}
+
+/// Encode `input` as base32 using `alphabet`, without padding
+pub(crate) fn encode(input: &[u8], alphabet: &[u8; 32]) -> String {
+    let mut encoded = String::with_capacity((input.len() * 8).div_ceil(5));
+    let mut buffer = 0u16;
+    let mut bits = 0;
+
+    for byte in input {
+        buffer = (buffer << 8) | *byte as u16;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(alphabet[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+
+    if bits > 0 {
+        encoded.push(alphabet[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+
+    encoded
+}
+
+/// Decode unpadded RFC 4648 base32 into `out`, rejecting trailing bits that are not zero
+pub(crate) fn decode_into(input: &[u8], out: &mut [u8]) -> Result<(), Error> {
+    if input.len() != (out.len() * 8).div_ceil(5) {
+        return Err(Error::InvalidLength(input.len()));
+    }
+
+    let mut buffer = 0u16;
+    let mut bits = 0;
+    let mut position = 0;
+
+    for character in input {
+        buffer = (buffer << 5) | decode_symbol(*character)? as u16;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            out[position] = (buffer >> bits) as u8;
+            position += 1;
+        }
+    }
+
+    if buffer & ((1 << bits) - 1) != 0 {
+        return Err(Error::InvalidEncoding);
+    }
+
+    Ok(())
+}
+
+impl WasmiumHashID {
+    /// Encode the `WasmiumHashID` as 71 upper case RFC 4648 base32 characters without padding
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let encoded = hash_id.to_base32();
+    ///
+    /// assert_eq!(encoded.len(), 71);
+    /// assert!(!encoded.contains('='));
+    /// ```
+    pub fn to_base32(&self) -> String {
+        encode(&self.build(), BASE32_CHARS)
+    }
+
+    /// Decode a `WasmiumHashID` from 71 unpadded RFC 4648 base32 characters in either case,
+    /// validating the TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let encoded = hash_id.to_base32();
+    ///
+    /// assert_eq!(WasmiumHashID::from_base32(&encoded), Ok(hash_id.clone()));
+    /// assert_eq!(WasmiumHashID::from_base32(&encoded.to_lowercase()), Ok(hash_id));
+    /// assert_eq!(WasmiumHashID::from_base32(&encoded[..70]), Err(Error::InvalidLength(70)));
+    /// assert_eq!(
+    ///     WasmiumHashID::from_base32(&format!("{}1", &encoded[..70])),
+    ///     Err(Error::InvalidEncoding)
+    /// );
+    /// ```
+    pub fn from_base32(encoded: &str) -> Result<WasmiumHashID, Error> {
+        let mut bytes = [0u8; ID_LEN];
+        decode_into(encoded.as_bytes(), &mut bytes)?;
+
+        WasmiumHashID::from_slice(&bytes)
+    }
+
+    /// Encode the `WasmiumHashID` as a filesystem safe name of 71 lower case base32
+    /// characters without padding, followed by `.extension` when one is given
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let filename = hash_id.to_filename(None);
+    ///
+    /// assert_eq!(filename.len(), 71);
+    /// assert!(filename.bytes().all(|byte| matches!(byte, b'a'..=b'z' | b'0'..=b'9')));
+    /// assert_eq!(hash_id.to_filename(Some("bin")), format!("{}.bin", filename));
+    /// ```
+    pub fn to_filename(&self, extension: Option<&str>) -> String {
+        let mut filename = self.to_base32().to_ascii_lowercase();
+
+        if let Some(extension) = extension {
+            filename.push('.');
+            filename.push_str(extension);
+        }
+
+        filename
+    }
+
+    /// Decode a `WasmiumHashID` from a name built by `to_filename`,
+    /// ignoring everything from the first `.` onwards
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    ///
+    /// assert_eq!(WasmiumHashID::from_filename(&hash_id.to_filename(None)), Ok(hash_id.clone()));
+    /// assert_eq!(
+    ///     WasmiumHashID::from_filename(&hash_id.to_filename(Some("tar.gz"))),
+    ///     Ok(hash_id)
+    /// );
+    /// ```
+    pub fn from_filename(name: &str) -> Result<WasmiumHashID, Error> {
+        let stem = name.split('.').next().unwrap_or(name);
+
+        WasmiumHashID::from_base32(stem)
+    }
+
+    /// Parse a `WasmiumHashID` from a URL path segment holding it as hex, base64url
+    /// or base32, trying the encodings in that order and returning the first valid ID.
+    /// base64url is only tried when the `base64` feature is enabled
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    ///
+    /// assert_eq!(WasmiumHashID::from_url_segment(&hash_id.to_hex()), Ok(hash_id.clone()));
+    /// assert_eq!(WasmiumHashID::from_url_segment(&hash_id.to_base32()), Ok(hash_id.clone()));
+    /// # #[cfg(feature = "base64")]
+    /// # {
+    /// assert_eq!(WasmiumHashID::from_url_segment(&hash_id.to_base64url()), Ok(hash_id));
+    /// # }
+    ///
+    /// assert_eq!(WasmiumHashID::from_url_segment("foo"), Err(Error::InvalidLength(3)));
+    /// ```
+    pub fn from_url_segment(s: &str) -> Result<WasmiumHashID, Error> {
+        let parsed = WasmiumHashID::parse_hex_into(s);
+
+        #[cfg(feature = "base64")]
+        let parsed = parsed.or_else(|_| WasmiumHashID::from_base64url(s));
+
+        parsed.or_else(|_| WasmiumHashID::from_base32(s))
+    }
+}
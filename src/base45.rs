@@ -0,0 +1,92 @@
+use crate::{Error, WasmiumHashID, ID_LEN};
+
+/// The RFC 9285 base45 alphabet, which is the QR code alphanumeric mode character set
+const BASE45_CHARS: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// The number of base45 characters for a `WasmiumHashID`, 3 for every 2 bytes
+const BASE45_ID_LEN: usize = ID_LEN / 2 * 3;
+
+/// Decode a single base45 character
+fn decode_symbol(character: u8) -> Result<u32, Error> {
+    BASE45_CHARS
+        .iter()
+        .position(|symbol| *symbol == character)
+        .map(|position| position as u32)
+        .ok_or(Error::InvalidEncoding)
+}
+
+impl WasmiumHashID {
+    /// Encode the `WasmiumHashID` as 66 characters of RFC 9285 base45, which only uses the
+    /// QR code alphanumeric character set so QR codes can use their densest text mode
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let encoded = hash_id.to_qr_alnum();
+    ///
+    /// assert_eq!(encoded.len(), 66);
+    /// assert!(encoded
+    ///     .chars()
+    ///     .all(|character| "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:".contains(character)));
+    /// ```
+    pub fn to_qr_alnum(&self) -> String {
+        let mut encoded = String::with_capacity(BASE45_ID_LEN);
+
+        for pair in self.build().chunks_exact(2) {
+            let mut value = ((pair[0] as u32) << 8) | pair[1] as u32;
+            for _ in 0..3 {
+                encoded.push(BASE45_CHARS[(value % 45) as usize] as char);
+                value /= 45;
+            }
+        }
+
+        encoded
+    }
+
+    /// Decode a `WasmiumHashID` from the 66 base45 characters built by `to_qr_alnum`,
+    /// validating the TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let encoded = hash_id.to_qr_alnum();
+    ///
+    /// assert_eq!(WasmiumHashID::from_qr_alnum(&encoded), Ok(hash_id));
+    /// assert_eq!(WasmiumHashID::from_qr_alnum(&encoded[..65]), Err(Error::InvalidLength(65)));
+    /// assert_eq!(
+    ///     WasmiumHashID::from_qr_alnum(&format!("{}a", &encoded[..65])),
+    ///     Err(Error::InvalidEncoding)
+    /// );
+    /// // ":::" is 45^3 - 1 which does not fit in 2 bytes
+    /// assert_eq!(
+    ///     WasmiumHashID::from_qr_alnum(&format!(":::{}", &encoded[3..])),
+    ///     Err(Error::InvalidEncoding)
+    /// );
+    /// ```
+    pub fn from_qr_alnum(encoded: &str) -> Result<WasmiumHashID, Error> {
+        if encoded.len() != BASE45_ID_LEN {
+            return Err(Error::InvalidLength(encoded.len()));
+        }
+
+        let mut bytes = [0u8; ID_LEN];
+        for (pair, triple) in bytes
+            .chunks_exact_mut(2)
+            .zip(encoded.as_bytes().chunks_exact(3))
+        {
+            let value = decode_symbol(triple[0])?
+                + decode_symbol(triple[1])? * 45
+                + decode_symbol(triple[2])? * 45 * 45;
+            if value > u16::MAX as u32 {
+                return Err(Error::InvalidEncoding);
+            }
+
+            pair.copy_from_slice(&(value as u16).to_be_bytes());
+        }
+
+        WasmiumHashID::from_slice(&bytes)
+    }
+}
@@ -0,0 +1,89 @@
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine,
+};
+
+use crate::{Error, WasmiumHashID};
+
+impl WasmiumHashID {
+    /// Encode the `WasmiumHashID` as standard base64 with padding
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let encoded = hash_id.to_base64_standard();
+    ///
+    /// assert_eq!(encoded.len(), 60);
+    /// assert!(encoded.ends_with('='));
+    /// ```
+    pub fn to_base64_standard(&self) -> String {
+        STANDARD.encode(self.build())
+    }
+
+    /// Decode a `WasmiumHashID` from standard base64 with padding,
+    /// validating the decoded length and the TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let encoded = hash_id.to_base64_standard();
+    /// assert_eq!(WasmiumHashID::from_base64_standard(&encoded), Ok(hash_id));
+    ///
+    /// // Missing padding is rejected
+    /// assert_eq!(
+    ///     WasmiumHashID::from_base64_standard(encoded.trim_end_matches('=')),
+    ///     Err(Error::InvalidEncoding)
+    /// );
+    /// ```
+    pub fn from_base64_standard(encoded: &str) -> Result<WasmiumHashID, Error> {
+        let bytes = STANDARD
+            .decode(encoded)
+            .map_err(|_| Error::InvalidEncoding)?;
+
+        WasmiumHashID::from_slice(&bytes)
+    }
+
+    /// Encode the `WasmiumHashID` as URL safe base64 without padding
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let encoded = hash_id.to_base64url();
+    ///
+    /// assert_eq!(encoded.len(), 59);
+    /// assert!(!encoded.contains(&['+', '/', '='][..]));
+    /// ```
+    pub fn to_base64url(&self) -> String {
+        URL_SAFE_NO_PAD.encode(self.build())
+    }
+
+    /// Decode a `WasmiumHashID` from URL safe base64 without padding,
+    /// validating the decoded length and the TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let encoded = hash_id.to_base64url();
+    /// assert_eq!(WasmiumHashID::from_base64url(&encoded), Ok(hash_id));
+    ///
+    /// assert_eq!(
+    ///     WasmiumHashID::from_base64url(&format!("{}=", encoded)),
+    ///     Err(Error::InvalidEncoding)
+    /// );
+    /// ```
+    pub fn from_base64url(encoded: &str) -> Result<WasmiumHashID, Error> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(encoded)
+            .map_err(|_| Error::InvalidEncoding)?;
+
+        WasmiumHashID::from_slice(&bytes)
+    }
+}
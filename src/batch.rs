@@ -0,0 +1,466 @@
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, ErrorKind, Read},
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use tai64::Tai64N;
+
+use crate::{Error, WasmiumHashID, HASH_LEN, ID_LEN, TIMESTAMP_LEN};
+
+/// The largest shard count accepted by `shard_distribution`
+const MAX_SHARDS: u64 = 1 << 16;
+
+impl WasmiumHashID {
+    /// Write a batch of `WasmiumHashID`s back to back into `out`,
+    /// reserving `ids.len() * ID_LEN` bytes up front
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{WasmiumHashID, ID_LEN};
+    ///
+    /// let ids = (0u32..1000)
+    ///     .map(|index| WasmiumHashID::new(blake3::hash(&index.to_le_bytes())))
+    ///     .collect::<Vec<WasmiumHashID>>();
+    ///
+    /// let mut buffer = Vec::new();
+    /// WasmiumHashID::write_batch(&ids, &mut buffer);
+    /// assert_eq!(buffer.len(), 1000 * ID_LEN);
+    ///
+    /// let decoded = WasmiumHashID::read_batch(&buffer).unwrap();
+    /// assert_eq!(decoded, ids);
+    /// ```
+    pub fn write_batch(ids: &[WasmiumHashID], out: &mut Vec<u8>) {
+        out.reserve(WasmiumHashID::encoded_len(ids.len()));
+        ids.iter().for_each(|id| out.extend_from_slice(&id.build()));
+    }
+
+    /// Read a batch of `WasmiumHashID`s written back to back by `write_batch`.
+    /// Errors if the length of `bytes` is not a multiple of `ID_LEN`
+    /// or if any of the timestamps is invalid
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let ids = vec![WasmiumHashID::new(blake3::hash(b"foo")), WasmiumHashID::new(blake3::hash(b"bar"))];
+    ///
+    /// let mut buffer = Vec::new();
+    /// WasmiumHashID::write_batch(&ids, &mut buffer);
+    /// assert_eq!(WasmiumHashID::read_batch(&buffer), Ok(ids));
+    ///
+    /// buffer.pop();
+    /// assert_eq!(WasmiumHashID::read_batch(&buffer), Err(Error::InvalidLength(87)));
+    /// ```
+    pub fn read_batch(bytes: &[u8]) -> Result<Vec<WasmiumHashID>, Error> {
+        let chunks = bytes.chunks_exact(ID_LEN);
+        if !chunks.remainder().is_empty() {
+            return Err(Error::InvalidLength(bytes.len()));
+        }
+
+        chunks.map(WasmiumHashID::from_slice).collect()
+    }
+
+    /// Generate a `WasmiumHashID` for every message stamped with one TAI64N timestamp captured
+    /// up front, so a batch created at one instant sorts together
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let messages: [&[u8]; 3] = [b"foo", b"bar", b"baz"];
+    /// let ids = WasmiumHashID::hash_batch_atomic(&messages);
+    ///
+    /// assert_eq!(ids.len(), 3);
+    /// assert!(ids.iter().all(|id| id.timestamp_bytes() == ids[0].timestamp_bytes()));
+    /// assert_eq!(ids[1].digest_bytes(), blake3::hash(b"bar").as_bytes());
+    /// ```
+    pub fn hash_batch_atomic(messages: &[&[u8]]) -> Vec<WasmiumHashID> {
+        let timestamp = Tai64N::now();
+
+        messages
+            .iter()
+            .map(|message| WasmiumHashID::new_with_timestamp(blake3::hash(message), timestamp))
+            .collect()
+    }
+
+    /// Get the number of bytes `write_batch` produces for `count` IDs, `count * ID_LEN`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// for count in [0usize, 1, 7, 100] {
+    ///     let ids = vec![WasmiumHashID::new(blake3::hash(b"foo")); count];
+    ///     let mut buffer = Vec::new();
+    ///     WasmiumHashID::write_batch(&ids, &mut buffer);
+    ///
+    ///     assert_eq!(WasmiumHashID::encoded_len(count), buffer.len());
+    /// }
+    /// ```
+    pub fn encoded_len(count: usize) -> usize {
+        count * ID_LEN
+    }
+
+    /// Get the number of bytes `borsh_serialize_batch` produces for `count` IDs,
+    /// the 4 byte length prefix followed by `count * ID_LEN`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// for count in [0usize, 1, 7, 100] {
+    ///     let ids = vec![WasmiumHashID::new(blake3::hash(b"foo")); count];
+    ///
+    ///     assert_eq!(
+    ///         WasmiumHashID::borsh_encoded_len(count),
+    ///         WasmiumHashID::borsh_serialize_batch(&ids).len()
+    ///     );
+    /// }
+    /// ```
+    pub fn borsh_encoded_len(count: usize) -> usize {
+        4 + WasmiumHashID::encoded_len(count)
+    }
+
+    /// Serialize a batch of `WasmiumHashID`s with borsh.
+    /// The output is a 4 byte little endian length prefix followed by `ID_LEN` bytes for each ID
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{WasmiumHashID, ID_LEN};
+    ///
+    /// let ids = (0u32..10)
+    ///     .map(|index| WasmiumHashID::new(blake3::hash(&index.to_le_bytes())))
+    ///     .collect::<Vec<WasmiumHashID>>();
+    ///
+    /// let bytes = WasmiumHashID::borsh_serialize_batch(&ids);
+    /// assert_eq!(bytes.len(), 4 + ids.len() * ID_LEN);
+    /// assert_eq!(&bytes[..4], &10u32.to_le_bytes());
+    /// assert_eq!(&bytes[4..4 + ID_LEN], &ids[0].build());
+    ///
+    /// assert_eq!(WasmiumHashID::borsh_deserialize_batch(&bytes), Ok(ids));
+    /// ```
+    pub fn borsh_serialize_batch(ids: &[WasmiumHashID]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(WasmiumHashID::borsh_encoded_len(ids.len()));
+        ids.serialize(&mut out)
+            .expect("Writing into a `Vec` never fails");
+
+        out
+    }
+
+    /// Deserialize a batch of `WasmiumHashID`s written by `borsh_serialize_batch`.
+    /// Just like the derived `BorshDeserialize`, the timestamps are not validated
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let ids = vec![WasmiumHashID::new(blake3::hash(b"foo"))];
+    /// let bytes = WasmiumHashID::borsh_serialize_batch(&ids);
+    ///
+    /// assert_eq!(WasmiumHashID::borsh_deserialize_batch(&bytes), Ok(ids));
+    /// assert!(WasmiumHashID::borsh_deserialize_batch(&bytes[..20]).is_err());
+    /// ```
+    pub fn borsh_deserialize_batch(bytes: &[u8]) -> Result<Vec<WasmiumHashID>, Error> {
+        Vec::<WasmiumHashID>::try_from_slice(bytes)
+            .map_err(|error| Error::Serialization(error.to_string()))
+    }
+
+    /// Find the Blake3 hashes shared by more than one `WasmiumHashID`, ignoring the timestamps.
+    /// Each group holds the indices of the IDs with that hash and
+    /// the groups are ordered by their first occurrence in `ids`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let ids = [
+    ///     WasmiumHashID::new(blake3::hash(b"foo")),
+    ///     WasmiumHashID::new(blake3::hash(b"bar")),
+    ///     WasmiumHashID::new(blake3::hash(b"foo")),
+    ///     WasmiumHashID::new(blake3::hash(b"baz")),
+    ///     WasmiumHashID::new(blake3::hash(b"bar")),
+    ///     WasmiumHashID::new(blake3::hash(b"foo")),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     WasmiumHashID::find_duplicate_digests(&ids),
+    ///     vec![
+    ///         (*blake3::hash(b"foo").as_bytes(), vec![0, 2, 5]),
+    ///         (*blake3::hash(b"bar").as_bytes(), vec![1, 4]),
+    ///     ]
+    /// );
+    /// ```
+    pub fn find_duplicate_digests(ids: &[WasmiumHashID]) -> Vec<([u8; HASH_LEN], Vec<usize>)> {
+        let mut positions: HashMap<[u8; HASH_LEN], usize> = HashMap::with_capacity(ids.len());
+        let mut groups: Vec<([u8; HASH_LEN], Vec<usize>)> = Vec::new();
+
+        for (index, id) in ids.iter().enumerate() {
+            match positions.get(&id.blake3hash) {
+                Some(&position) => groups[position].1.push(index),
+                None => {
+                    positions.insert(id.blake3hash, groups.len());
+                    groups.push((id.blake3hash, vec![index]));
+                }
+            }
+        }
+
+        groups.retain(|(_, indices)| indices.len() > 1);
+
+        groups
+    }
+
+    /// Count how many `WasmiumHashID`s land in each of `shards` buckets by `ring_position`,
+    /// `fingerprint64() % shards`, to check that IDs spread evenly. The shard count is checked
+    /// before anything is allocated, errors with `Error::InvalidShardCount` if it is zero or above 65536
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let ids = (0u32..10_000)
+    ///     .map(|index| WasmiumHashID::new(blake3::hash(&index.to_le_bytes())))
+    ///     .collect::<Vec<WasmiumHashID>>();
+    ///
+    /// let distribution = WasmiumHashID::shard_distribution(&ids, 10).unwrap();
+    /// assert_eq!(distribution.len(), 10);
+    /// assert_eq!(distribution.iter().sum::<u64>(), 10_000);
+    /// assert!(distribution.iter().all(|count| (850..=1150).contains(count)));
+    ///
+    /// assert_eq!(WasmiumHashID::shard_distribution(&ids, 0), Err(Error::InvalidShardCount(0)));
+    /// assert_eq!(
+    ///     WasmiumHashID::shard_distribution(&ids, u64::MAX),
+    ///     Err(Error::InvalidShardCount(u64::MAX))
+    /// );
+    /// ```
+    pub fn shard_distribution(ids: &[WasmiumHashID], shards: u64) -> Result<Vec<u64>, Error> {
+        if shards == 0 || shards > MAX_SHARDS {
+            return Err(Error::InvalidShardCount(shards));
+        }
+
+        let mut distribution = vec![0u64; shards as usize];
+        for id in ids {
+            distribution[id.ring_position(shards)? as usize] += 1;
+        }
+
+        Ok(distribution)
+    }
+
+    /// Read a batch of `WasmiumHashID`s from untrusted bytes, validating every entry.
+    /// On failure returns the index of the first malformed ID with the reason,
+    /// a trailing partial entry is reported as `Error::InvalidLength` with its length
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let ids = (0u32..4)
+    ///     .map(|index| WasmiumHashID::new(blake3::hash(&index.to_le_bytes())))
+    ///     .collect::<Vec<WasmiumHashID>>();
+    ///
+    /// let mut buffer = Vec::new();
+    /// WasmiumHashID::write_batch(&ids, &mut buffer);
+    /// assert_eq!(WasmiumHashID::parse_batch_checked(&buffer), Ok(ids));
+    ///
+    /// // Corrupt the nanoseconds of the third entry
+    /// buffer[2 * 44 + 8..2 * 44 + 12].copy_from_slice(&u32::MAX.to_be_bytes());
+    /// assert_eq!(
+    ///     WasmiumHashID::parse_batch_checked(&buffer),
+    ///     Err((2, Error::Timestamp(tai64::Error::NanosInvalid)))
+    /// );
+    ///
+    /// assert_eq!(
+    ///     WasmiumHashID::parse_batch_checked(&buffer[..50]),
+    ///     Err((1, Error::InvalidLength(6)))
+    /// );
+    /// ```
+    pub fn parse_batch_checked(bytes: &[u8]) -> Result<Vec<WasmiumHashID>, (usize, Error)> {
+        bytes
+            .chunks(ID_LEN)
+            .enumerate()
+            .map(|(index, chunk)| WasmiumHashID::from_slice(chunk).map_err(|error| (index, error)))
+            .collect()
+    }
+
+    /// Lazily read `WasmiumHashID`s stored back to back from `reader`, `ID_LEN` bytes at a time.
+    /// The iterator ends cleanly at the end of the input,
+    /// a partial trailing record yields `Error::InvalidLength` with the number of bytes read
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let ids = (0u32..3)
+    ///     .map(|index| WasmiumHashID::new(blake3::hash(&index.to_le_bytes())))
+    ///     .collect::<Vec<WasmiumHashID>>();
+    ///
+    /// let mut buffer = Vec::new();
+    /// WasmiumHashID::write_batch(&ids, &mut buffer);
+    /// buffer.extend_from_slice(&[0u8; 10]);
+    ///
+    /// let decoded = WasmiumHashID::iter_reader(Cursor::new(buffer)).collect::<Vec<_>>();
+    /// assert_eq!(decoded.len(), 4);
+    /// assert_eq!(decoded[..3], ids.into_iter().map(Ok).collect::<Vec<_>>()[..]);
+    /// assert_eq!(decoded[3], Err(Error::InvalidLength(10)));
+    /// ```
+    pub fn iter_reader<R: Read>(
+        mut reader: R,
+    ) -> impl Iterator<Item = Result<WasmiumHashID, Error>> {
+        let mut finished = false;
+
+        std::iter::from_fn(move || {
+            if finished {
+                return None;
+            }
+
+            let mut record = [0u8; ID_LEN];
+            let mut filled = 0;
+            while filled < ID_LEN {
+                match reader.read(&mut record[filled..]) {
+                    Ok(0) => break,
+                    Ok(read) => filled += read,
+                    Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                    Err(error) => {
+                        finished = true;
+                        return Some(Err(error.into()));
+                    }
+                }
+            }
+
+            match filled {
+                0 => {
+                    finished = true;
+                    None
+                }
+                ID_LEN => Some(WasmiumHashID::from_slice(&record)),
+                _ => {
+                    finished = true;
+                    Some(Err(Error::InvalidLength(filled)))
+                }
+            }
+        })
+    }
+
+    /// Read a `WasmiumHashID` framed by a 1 byte length prefix, which must equal `ID_LEN`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    ///
+    /// let mut framed = vec![44u8];
+    /// framed.extend_from_slice(&hash_id.build());
+    /// assert_eq!(
+    ///     WasmiumHashID::read_length_prefixed(&mut Cursor::new(&framed)),
+    ///     Ok(hash_id)
+    /// );
+    ///
+    /// framed[0] = 32;
+    /// assert_eq!(
+    ///     WasmiumHashID::read_length_prefixed(&mut Cursor::new(&framed)),
+    ///     Err(Error::InvalidLength(32))
+    /// );
+    /// ```
+    pub fn read_length_prefixed<R: Read>(r: &mut R) -> Result<WasmiumHashID, Error> {
+        let mut length = [0u8; 1];
+        r.read_exact(&mut length)?;
+        if length[0] as usize != ID_LEN {
+            return Err(Error::InvalidLength(length[0] as usize));
+        }
+
+        let mut record = [0u8; ID_LEN];
+        r.read_exact(&mut record)?;
+
+        WasmiumHashID::from_slice(&record)
+    }
+
+    /// Split a batch of `WasmiumHashID`s into parallel columns of TAI64N timestamps
+    /// and Blake3 hashes
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let ids = vec![WasmiumHashID::new(blake3::hash(b"foo")), WasmiumHashID::new(blake3::hash(b"bar"))];
+    /// let (timestamps, digests) = WasmiumHashID::to_columnar(&ids);
+    ///
+    /// assert_eq!(&timestamps[1], ids[1].timestamp_bytes());
+    /// assert_eq!(&digests[1], blake3::hash(b"bar").as_bytes());
+    /// assert_eq!(WasmiumHashID::from_columnar(&timestamps, &digests), Ok(ids));
+    /// ```
+    pub fn to_columnar(ids: &[WasmiumHashID]) -> (Vec<[u8; TIMESTAMP_LEN]>, Vec<[u8; HASH_LEN]>) {
+        ids.iter()
+            .map(|id| (id.tai_timestamp, id.blake3hash))
+            .unzip()
+    }
+
+    /// Join parallel columns of TAI64N timestamps and Blake3 hashes built by `to_columnar`
+    /// back into `WasmiumHashID`s, validating every timestamp.
+    /// Errors with `Error::InvalidLength` holding the number of hashes if the columns differ in length
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let ids = vec![WasmiumHashID::new(blake3::hash(b"foo")), WasmiumHashID::new(blake3::hash(b"bar"))];
+    /// let (timestamps, mut digests) = WasmiumHashID::to_columnar(&ids);
+    ///
+    /// digests.pop();
+    /// assert_eq!(
+    ///     WasmiumHashID::from_columnar(&timestamps, &digests),
+    ///     Err(Error::InvalidLength(1))
+    /// );
+    /// ```
+    pub fn from_columnar(
+        timestamps: &[[u8; TIMESTAMP_LEN]],
+        digests: &[[u8; HASH_LEN]],
+    ) -> Result<Vec<WasmiumHashID>, Error> {
+        if timestamps.len() != digests.len() {
+            return Err(Error::InvalidLength(digests.len()));
+        }
+
+        timestamps
+            .iter()
+            .zip(digests)
+            .map(|(timestamp, digest)| {
+                Tai64N::from_slice(timestamp)?;
+
+                Ok(WasmiumHashID {
+                    tai_timestamp: *timestamp,
+                    blake3hash: *digest,
+                })
+            })
+            .collect()
+    }
+
+    /// Generate a `WasmiumHashID` for every line of `reader`, hashing the line without its
+    /// `\n` or `\r\n` ending. Lines are read as bytes so they do not need to be UTF-8
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let ids = WasmiumHashID::from_lines(Cursor::new(b"foo\nbar\r\n\nbaz")).unwrap();
+    ///
+    /// assert_eq!(ids.len(), 4);
+    /// for (id, line) in ids.iter().zip([&b"foo"[..], b"bar", b"", b"baz"]) {
+    ///     assert_eq!(id.digest_bytes(), blake3::hash(line).as_bytes());
+    /// }
+    /// ```
+    pub fn from_lines<R: BufRead>(reader: R) -> io::Result<Vec<WasmiumHashID>> {
+        reader
+            .split(b'\n')
+            .map(|line| {
+                let line = line?;
+                let line = line.strip_suffix(b"\r").unwrap_or(&line);
+
+                Ok(WasmiumHashID::new(blake3::hash(line)))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{Error, WasmiumHashID};
+
+impl WasmiumHashID {
+    /// Generate a `WasmiumHashID` from the canonical serialization of `value`.
+    ///
+    /// The canonical format is compact JSON produced by first converting `value` into a
+    /// `serde_json::Value` and then rebuilding every object with its keys in sorted order,
+    /// equal values therefore hash identically no matter the iteration order of the maps
+    /// they were built from or whether the `preserve_order` feature of `serde_json` is enabled.
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use serde::Serialize;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Document {
+    ///     title: String,
+    ///     tags: HashMap<String, u32>,
+    /// }
+    ///
+    /// let mut first_tags = HashMap::new();
+    /// let mut second_tags = HashMap::new();
+    /// for index in 0..32u32 {
+    ///     first_tags.insert(format!("tag{}", index), index);
+    ///     second_tags.insert(format!("tag{}", 31 - index), 31 - index);
+    /// }
+    ///
+    /// let first = Document { title: "Foo".to_owned(), tags: first_tags };
+    /// let second = Document { title: "Foo".to_owned(), tags: second_tags };
+    ///
+    /// let first_id = WasmiumHashID::from_serializable(&first).unwrap().build();
+    /// let second_id = WasmiumHashID::from_serializable(&second).unwrap().build();
+    ///
+    /// assert_eq!(
+    ///     WasmiumHashID::get_blake3_hash(first_id).unwrap(),
+    ///     WasmiumHashID::get_blake3_hash(second_id).unwrap()
+    /// );
+    ///
+    /// let value = serde_json::json!({ "b": 1, "a": [{ "d": 2, "c": 3 }] });
+    /// let hash_id = WasmiumHashID::from_serializable(&value).unwrap().build();
+    /// assert_eq!(
+    ///     WasmiumHashID::get_blake3_hash(hash_id).unwrap(),
+    ///     blake3::hash(br#"{"a":[{"c":3,"d":2}],"b":1}"#)
+    /// );
+    /// ```
+    pub fn from_serializable<T: Serialize>(value: &T) -> Result<WasmiumHashID, Error> {
+        let canonical = serde_json::to_value(value)
+            .and_then(|value| serde_json::to_vec(&canonicalize(value)))
+            .map_err(|error| Error::Serialization(error.to_string()))?;
+
+        Ok(WasmiumHashID::new(blake3::hash(&canonical)))
+    }
+}
+
+/// Rebuild every object in `value` with its keys inserted in sorted order
+fn canonicalize(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let sorted = object
+                .into_iter()
+                .map(|(key, value)| (key, canonicalize(value)))
+                .collect::<BTreeMap<String, Value>>();
+
+            Value::Object(sorted.into_iter().collect::<Map<String, Value>>())
+        }
+        Value::Array(array) => Value::Array(array.into_iter().map(canonicalize).collect()),
+        value => value,
+    }
+}
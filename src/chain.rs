@@ -0,0 +1,81 @@
+use crate::WasmiumHashID;
+
+impl WasmiumHashID {
+    /// Hash `message` linked to the `WasmiumHashID` before it in the chain
+    fn chain_link(previous: &WasmiumHashID, message: &[u8]) -> blake3::Hash {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&previous.build());
+        hasher.update(message);
+
+        hasher.finalize()
+    }
+
+    /// Build a tamper evident chain of `WasmiumHashID`s, one per message.
+    /// Each Blake3 hash covers the full previous ID, timestamp included, followed by the message,
+    /// starting from `genesis`. Every ID is stamped with the current TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let genesis = WasmiumHashID::new(blake3::hash(b"genesis"));
+    /// let messages: [&[u8]; 5] = [b"foo", b"bar", b"baz", b"qux", b"quux"];
+    ///
+    /// let chain = WasmiumHashID::chain_messages(&genesis, &messages);
+    /// assert_eq!(chain.len(), 5);
+    ///
+    /// let mut linked = genesis.build().to_vec();
+    /// linked.extend_from_slice(b"foo");
+    /// assert_eq!(chain[0].digest_bytes(), blake3::hash(&linked).as_bytes());
+    /// ```
+    pub fn chain_messages(genesis: &WasmiumHashID, messages: &[&[u8]]) -> Vec<WasmiumHashID> {
+        let mut chain: Vec<WasmiumHashID> = Vec::with_capacity(messages.len());
+
+        for message in messages {
+            let previous = chain.last().unwrap_or(genesis);
+            let hash_id = WasmiumHashID::new(WasmiumHashID::chain_link(previous, message));
+            chain.push(hash_id);
+        }
+
+        chain
+    }
+
+    /// Verify a chain built by `chain_messages` against its genesis and messages,
+    /// returning the index of the first ID that does not link up
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let genesis = WasmiumHashID::new(blake3::hash(b"genesis"));
+    /// let mut messages: [&[u8]; 5] = [b"foo", b"bar", b"baz", b"qux", b"quux"];
+    ///
+    /// let chain = WasmiumHashID::chain_messages(&genesis, &messages);
+    /// assert_eq!(WasmiumHashID::verify_chained_batch(&genesis, &messages, &chain), Ok(()));
+    ///
+    /// messages[2] = b"tampered";
+    /// assert_eq!(WasmiumHashID::verify_chained_batch(&genesis, &messages, &chain), Err(2));
+    ///
+    /// assert_eq!(WasmiumHashID::verify_chained_batch(&genesis, &messages[..2], &chain), Err(2));
+    /// ```
+    pub fn verify_chained_batch(
+        genesis: &WasmiumHashID,
+        messages: &[&[u8]],
+        chain: &[WasmiumHashID],
+    ) -> Result<(), usize> {
+        let mut previous = genesis;
+
+        for (index, (message, hash_id)) in messages.iter().zip(chain).enumerate() {
+            if WasmiumHashID::chain_link(previous, message).as_bytes() != hash_id.digest_bytes() {
+                return Err(index);
+            }
+            previous = hash_id;
+        }
+
+        if messages.len() == chain.len() {
+            Ok(())
+        } else {
+            Err(messages.len().min(chain.len()))
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use tai64::{Tai64, Tai64N};
+
+use std::time::SystemTime;
+
+use crate::{hex, Error, WasmiumHashID, HASH_LEN, ID_LEN, TIMESTAMP_LEN};
+
+impl WasmiumHashID {
+    /// Format the TAI64N timestamp as an RFC3339 UTC string with second precision.
+    /// Errors with `Error::OutOfRange` if the timestamp is outside the range of `chrono::DateTime`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::{Tai64, Tai64N};
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let timestamp = Tai64N::UNIX_EPOCH + Duration::new(1_704_164_645, 500);
+    /// let hash_id = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), timestamp).build();
+    ///
+    /// assert_eq!(
+    ///     WasmiumHashID::timestamp_rfc3339(hash_id),
+    ///     Ok("2024-01-02T03:04:05Z".to_owned())
+    /// );
+    ///
+    /// let ancient = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), Tai64N(Tai64(0), 0));
+    /// assert_eq!(
+    ///     WasmiumHashID::timestamp_rfc3339(ancient.build()),
+    ///     Err(Error::OutOfRange)
+    /// );
+    /// ```
+    pub fn timestamp_rfc3339(hash_id: [u8; ID_LEN]) -> Result<String, Error> {
+        let timestamp = Tai64N::from_slice(&hash_id[..TIMESTAMP_LEN])?;
+
+        Ok(to_datetime(&timestamp)?.to_rfc3339_opts(SecondsFormat::Secs, true))
+    }
+
+    /// Describe a `WasmiumHashID` byte array as a multi line block for CLI output,
+    /// holding the RFC3339 timestamp, the raw TAI64N hex, the Blake3 hash hex and the short form.
+    /// Errors with `Error::OutOfRange` if the timestamp is outside the range of `chrono::DateTime`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::{Tai64, Tai64N};
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let timestamp = Tai64N::UNIX_EPOCH + Duration::from_secs(1_704_164_645);
+    /// let blake3_hash = blake3::hash(b"foo");
+    /// let hash_id = WasmiumHashID::new_with_timestamp(blake3_hash, timestamp);
+    ///
+    /// let description = WasmiumHashID::describe(hash_id.build()).unwrap();
+    /// assert!(description.contains("timestamp: 2024-01-02T03:04:05Z"));
+    /// assert!(description.contains(&format!("tai64n:    {}", &hash_id.to_hex()[..24])));
+    /// assert!(description.contains(&format!("digest:    {}", blake3_hash.to_hex())));
+    /// assert!(description.contains(&format!("short:     {}", hash_id.short())));
+    ///
+    /// let ancient = WasmiumHashID::new_with_timestamp(blake3_hash, Tai64N(Tai64(0), 0));
+    /// assert_eq!(WasmiumHashID::describe(ancient.build()), Err(Error::OutOfRange));
+    /// ```
+    pub fn describe(hash_id: [u8; ID_LEN]) -> Result<String, Error> {
+        let timestamp = WasmiumHashID::timestamp_rfc3339(hash_id)?;
+        let parsed = WasmiumHashID::from_slice(&hash_id)?;
+        let hex = parsed.to_hex();
+
+        Ok(format!(
+            "timestamp: {}\ntai64n:    {}\ndigest:    {}\nshort:     {}",
+            timestamp,
+            &hex[..TIMESTAMP_LEN * 2],
+            &hex[TIMESTAMP_LEN * 2..],
+            parsed.short()
+        ))
+    }
+
+    /// Get the fields attached to log records for a `WasmiumHashID` byte array,
+    /// the short Blake3 hash hex and the RFC3339 timestamp. Errors if the timestamp is invalid,
+    /// or with `Error::OutOfRange` if it is outside the range of `chrono::DateTime`, and never panics
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use chrono::DateTime;
+    /// use tai64::{Tai64, Tai64N};
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let timestamp = Tai64N::UNIX_EPOCH + Duration::from_secs(1_704_164_645);
+    /// let blake3_hash = blake3::hash(b"foo");
+    /// let hash_id = WasmiumHashID::new_with_timestamp(blake3_hash, timestamp).build();
+    ///
+    /// let (digest, time) = WasmiumHashID::as_log_fields(hash_id).unwrap();
+    /// assert_eq!(digest, blake3_hash.to_hex()[..8]);
+    /// assert_eq!(
+    ///     DateTime::parse_from_rfc3339(&time).unwrap().timestamp(),
+    ///     1_704_164_645
+    /// );
+    ///
+    /// let far_future = WasmiumHashID::new_with_timestamp(blake3_hash, Tai64N(Tai64(u64::MAX >> 1), 0));
+    /// assert_eq!(WasmiumHashID::as_log_fields(far_future.build()), Err(Error::OutOfRange));
+    /// ```
+    pub fn as_log_fields(hash_id: [u8; ID_LEN]) -> Result<(String, String), Error> {
+        let timestamp = WasmiumHashID::timestamp_rfc3339(hash_id)?;
+        let parsed = WasmiumHashID::from_slice(&hash_id)?;
+
+        Ok((parsed.short(), timestamp))
+    }
+
+    /// Get the signed time elapsed from the TAI64N timestamp of `earlier` to that of `later`,
+    /// negative when `later` is actually the earlier of the two. Errors if either timestamp is
+    /// invalid or with `Error::OutOfRange` if the interval does not fit a `chrono::Duration`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let timestamp = Tai64N::now();
+    /// let start = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), timestamp);
+    /// let end = WasmiumHashID::new_with_timestamp(
+    ///     blake3::hash(b"bar"),
+    ///     timestamp + Duration::from_millis(1_500),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     WasmiumHashID::elapsed_between(&start, &end),
+    ///     Ok(chrono::Duration::milliseconds(1_500))
+    /// );
+    /// assert_eq!(
+    ///     WasmiumHashID::elapsed_between(&end, &start),
+    ///     Ok(chrono::Duration::milliseconds(-1_500))
+    /// );
+    /// ```
+    pub fn elapsed_between(earlier: &Self, later: &Self) -> Result<chrono::Duration, Error> {
+        let to_chrono =
+            |duration| chrono::Duration::from_std(duration).map_err(|_| Error::OutOfRange);
+
+        match later.tai64n()?.duration_since(&earlier.tai64n()?) {
+            Ok(elapsed) => to_chrono(elapsed),
+            Err(reversed) => to_chrono(reversed).map(|reversed| -reversed),
+        }
+    }
+
+    /// Export a `WasmiumHashID` byte array as two CSV columns, the RFC3339 UTC timestamp with
+    /// nanosecond precision and the Blake3 hash as 64 lower case hex characters.
+    /// Errors if the timestamp is invalid or outside the range of `chrono::DateTime`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::{Tai64, Tai64N};
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let timestamp = Tai64N::UNIX_EPOCH + Duration::new(1_704_164_645, 500);
+    /// let blake3_hash = blake3::hash(b"foo");
+    /// let hash_id = WasmiumHashID::new_with_timestamp(blake3_hash, timestamp);
+    ///
+    /// let (time, digest) = WasmiumHashID::to_csv_row(hash_id.build()).unwrap();
+    /// assert_eq!(time, "2024-01-02T03:04:05.000000500Z");
+    /// assert_eq!(digest, blake3_hash.to_hex().as_str());
+    /// assert_eq!(WasmiumHashID::from_csv_row(&time, &digest), Ok(hash_id));
+    ///
+    /// let ancient = WasmiumHashID::new_with_timestamp(blake3_hash, Tai64N(Tai64(0), 0));
+    /// assert_eq!(WasmiumHashID::to_csv_row(ancient.build()), Err(Error::OutOfRange));
+    /// ```
+    pub fn to_csv_row(hash_id: [u8; ID_LEN]) -> Result<(String, String), Error> {
+        let parsed = WasmiumHashID::from_slice(&hash_id)?;
+        let datetime = to_datetime(&parsed.tai64n()?)?;
+        let digest = parsed
+            .digest_hex_array()
+            .iter()
+            .map(|character| *character as char)
+            .collect();
+
+        Ok((datetime.to_rfc3339_opts(SecondsFormat::Nanos, true), digest))
+    }
+
+    /// Import a `WasmiumHashID` from the two CSV columns written by `to_csv_row`. Timestamps
+    /// with any UTC offset are accepted, a malformed timestamp is reported as `Error::InvalidEncoding`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let (time, digest) = WasmiumHashID::to_csv_row(hash_id.build()).unwrap();
+    ///
+    /// assert_eq!(WasmiumHashID::from_csv_row(&time, &digest[..63]), Err(Error::InvalidLength(63)));
+    /// assert_eq!(
+    ///     WasmiumHashID::from_csv_row(&time, &format!("x{}", &digest[1..])),
+    ///     Err(Error::InvalidEncoding)
+    /// );
+    /// assert_eq!(WasmiumHashID::from_csv_row("yesterday", &digest), Err(Error::InvalidEncoding));
+    /// ```
+    pub fn from_csv_row(timestamp: &str, digest: &str) -> Result<WasmiumHashID, Error> {
+        let mut blake3hash = [0u8; HASH_LEN];
+        hex::decode_into(digest.as_bytes(), &mut blake3hash)?;
+
+        let datetime =
+            DateTime::parse_from_rfc3339(timestamp).map_err(|_| Error::InvalidEncoding)?;
+        let timestamp = Tai64N::from_system_time(&SystemTime::from(datetime));
+
+        Ok(WasmiumHashID::new_with_timestamp(
+            blake3hash.into(),
+            timestamp,
+        ))
+    }
+}
+
+/// Convert a TAI64N timestamp into a `chrono::DateTime`, erroring with `Error::OutOfRange`
+/// instead of panicking when the timestamp cannot be represented
+fn to_datetime(timestamp: &Tai64N) -> Result<DateTime<Utc>, Error> {
+    let Tai64N(Tai64(seconds), nanos) = timestamp;
+    let Tai64(unix_epoch) = Tai64::UNIX_EPOCH;
+    let unix_seconds =
+        i64::try_from(*seconds as i128 - unix_epoch as i128).map_err(|_| Error::OutOfRange)?;
+
+    DateTime::<Utc>::from_timestamp(unix_seconds, *nanos).ok_or(Error::OutOfRange)
+}
@@ -0,0 +1,35 @@
+use cid::{multihash::Multihash, Cid};
+
+use crate::WasmiumHashID;
+
+/// The multicodec code of the raw binary codec
+const RAW_CODEC: u64 = 0x55;
+
+/// The multicodec code of the Blake3 multihash
+const BLAKE3_MULTIHASH: u64 = 0x1e;
+
+impl WasmiumHashID {
+    /// Convert the Blake3 hash into a CIDv1 using the raw codec and a Blake3 multihash.
+    /// The TAI64N timestamp is not part of the CID and is dropped
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use cid::Cid;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let blake3_hash = blake3::hash(b"foo");
+    /// let cid = WasmiumHashID::new(blake3_hash).to_cid();
+    ///
+    /// let decoded = Cid::try_from(cid.to_string()).unwrap();
+    /// assert_eq!(decoded.version(), cid::Version::V1);
+    /// assert_eq!(decoded.codec(), 0x55);
+    /// assert_eq!(decoded.hash().code(), 0x1e);
+    /// assert_eq!(decoded.hash().digest(), blake3_hash.as_bytes());
+    /// ```
+    pub fn to_cid(&self) -> Cid {
+        let multihash = Multihash::wrap(BLAKE3_MULTIHASH, &self.blake3hash)
+            .expect("A 32 byte Blake3 hash always fits in a 64 byte multihash");
+
+        Cid::new_v1(RAW_CODEC, multihash)
+    }
+}
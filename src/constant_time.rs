@@ -0,0 +1,52 @@
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::{WasmiumHashID, HASH_LEN};
+
+impl WasmiumHashID {
+    /// Select `a` when `choice` is 0 or `b` when `choice` is 1 without branching on `choice`,
+    /// so the selected `WasmiumHashID` is not revealed through timing
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use subtle::Choice;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let a = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let b = WasmiumHashID::new(blake3::hash(b"bar"));
+    ///
+    /// assert_eq!(WasmiumHashID::conditional_select(&a, &b, Choice::from(0)), a);
+    /// assert_eq!(WasmiumHashID::conditional_select(&a, &b, Choice::from(1)), b);
+    /// ```
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> WasmiumHashID {
+        let mut selected = a.clone();
+        selected
+            .tai_timestamp
+            .iter_mut()
+            .zip(&b.tai_timestamp)
+            .chain(selected.blake3hash.iter_mut().zip(&b.blake3hash))
+            .for_each(|(byte, other)| byte.conditional_assign(other, choice));
+
+        selected
+    }
+
+    /// Check whether the Blake3 hash equals `digest` in constant time. All 32 bytes are
+    /// always compared and the result is only turned into a `bool` at the end, so the
+    /// position of the first differing byte is not revealed through timing
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    ///
+    /// assert!(hash_id.digest_ct_eq(blake3::hash(b"foo").as_bytes()));
+    /// assert!(!hash_id.digest_ct_eq(blake3::hash(b"bar").as_bytes()));
+    ///
+    /// let mut last_differs = *blake3::hash(b"foo").as_bytes();
+    /// last_differs[31] ^= 1;
+    /// assert!(!hash_id.digest_ct_eq(&last_differs));
+    /// ```
+    pub fn digest_ct_eq(&self, digest: &[u8; HASH_LEN]) -> bool {
+        self.blake3hash.ct_eq(digest).into()
+    }
+}
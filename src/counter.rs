@@ -0,0 +1,60 @@
+use crate::WasmiumHashID;
+
+/// Generate `WasmiumHashID`s with an incrementing counter mixed into the hashed message,
+/// so identical messages hashed within the same TAI64N tick still get distinct Blake3 hashes
+///
+/// #### Usage
+/// ```rust
+/// use std::collections::HashSet;
+/// use wasmium_hash_id::WasmiumHashIDCounter;
+///
+/// let mut counter = WasmiumHashIDCounter::new();
+/// let digests: HashSet<[u8; 32]> = (0..1000)
+///     .map(|_| *counter.next(b"foo").digest_bytes())
+///     .collect();
+///
+/// assert_eq!(digests.len(), 1000);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WasmiumHashIDCounter {
+    counter: u64,
+}
+
+impl WasmiumHashIDCounter {
+    /// Create a new counter starting at zero
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashIDCounter;
+    ///
+    /// let counter = WasmiumHashIDCounter::new();
+    /// ```
+    pub fn new() -> Self {
+        WasmiumHashIDCounter::default()
+    }
+
+    /// Generate a `WasmiumHashID` from the Blake3 hash of `message || counter`,
+    /// with the counter as big endian bytes, then increment the counter
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashIDCounter;
+    ///
+    /// let mut counter = WasmiumHashIDCounter::new();
+    /// let first = counter.next(b"foo");
+    /// let second = counter.next(b"foo");
+    ///
+    /// let mut preimage = b"foo".to_vec();
+    /// preimage.extend_from_slice(&1u64.to_be_bytes());
+    /// assert_eq!(second.digest_bytes(), blake3::hash(&preimage).as_bytes());
+    /// assert_ne!(first.digest_bytes(), second.digest_bytes());
+    /// ```
+    pub fn next(&mut self, message: &[u8]) -> WasmiumHashID {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(message);
+        hasher.update(&self.counter.to_be_bytes());
+        self.counter = self.counter.wrapping_add(1);
+
+        WasmiumHashID::new(hasher.finalize())
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{Error, WasmiumHashID, ID_LEN};
+
+impl WasmiumHashID {
+    /// Build the `WasmiumHashID` followed by the big-endian CRC32 of its 44 bytes, catching
+    /// accidental bit flips on disk or on the wire, including in the timestamp which the
+    /// Blake3 hash does not cover
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let bytes = hash_id.to_bytes_crc();
+    ///
+    /// assert_eq!(&bytes[..44], &hash_id.build());
+    /// assert_eq!(bytes[44..], crc32fast::hash(&hash_id.build()).to_be_bytes());
+    /// ```
+    pub fn to_bytes_crc(&self) -> [u8; ID_LEN + 4] {
+        let built = self.build();
+
+        let mut bytes = [0u8; ID_LEN + 4];
+        bytes[..ID_LEN].copy_from_slice(&built);
+        bytes[ID_LEN..].copy_from_slice(&crc32fast::hash(&built).to_be_bytes());
+
+        bytes
+    }
+
+    /// Restore a `WasmiumHashID` from a byte array built by `to_bytes_crc`,
+    /// verifying the CRC32 and the TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let mut bytes = hash_id.to_bytes_crc();
+    /// assert_eq!(WasmiumHashID::from_bytes_crc(bytes), Ok(hash_id));
+    ///
+    /// bytes[5] ^= 0x10;
+    /// assert_eq!(WasmiumHashID::from_bytes_crc(bytes), Err(Error::InvalidChecksum));
+    /// ```
+    pub fn from_bytes_crc(bytes: [u8; ID_LEN + 4]) -> Result<WasmiumHashID, Error> {
+        let mut crc = [0u8; 4];
+        crc.copy_from_slice(&bytes[ID_LEN..]);
+
+        if crc32fast::hash(&bytes[..ID_LEN]) != u32::from_be_bytes(crc) {
+            return Err(Error::InvalidChecksum);
+        }
+
+        WasmiumHashID::from_slice(&bytes[..ID_LEN])
+    }
+}
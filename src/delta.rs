@@ -0,0 +1,152 @@
+use crate::{Error, WasmiumHashID, HASH_LEN, ID_LEN, TIMESTAMP_LEN};
+
+/// The maximum number of bytes a LEB128 encoded `u64` takes
+const MAX_VARINT_LEN: usize = 10;
+
+/// Append `value` to `out` as an unsigned LEB128 varint
+fn write_varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Read an unsigned LEB128 varint from the start of `bytes`,
+/// returning the value and the number of bytes it took
+fn read_varint(bytes: &[u8]) -> Result<(u64, usize), Error> {
+    let mut value = 0u64;
+
+    for (index, byte) in bytes.iter().take(MAX_VARINT_LEN).enumerate() {
+        let bits = (*byte & 0x7f) as u64;
+        if index == MAX_VARINT_LEN - 1 && bits > 1 {
+            return Err(Error::InvalidEncoding);
+        }
+        value |= bits << (7 * index);
+
+        if byte & 0x80 == 0 {
+            return Ok((value, index + 1));
+        }
+    }
+
+    if bytes.len() < MAX_VARINT_LEN {
+        Err(Error::InvalidLength(bytes.len()))
+    } else {
+        Err(Error::InvalidEncoding)
+    }
+}
+
+/// Split a TAI64N timestamp into its seconds label and nanosecond subfield without validating it
+fn split_timestamp(timestamp: &[u8; TIMESTAMP_LEN]) -> (u64, [u8; 4]) {
+    let mut seconds = [0u8; 8];
+    seconds.copy_from_slice(&timestamp[..8]);
+    let mut nanos = [0u8; 4];
+    nanos.copy_from_slice(&timestamp[8..]);
+
+    (u64::from_be_bytes(seconds), nanos)
+}
+
+impl WasmiumHashID {
+    /// Encode a batch of `WasmiumHashID`s for storage when their timestamps are clustered.
+    /// The first ID is stored in full, every following ID is stored as the difference of its
+    /// TAI64 seconds label to the previous one as a zigzag LEB128 varint,
+    /// followed by its 4 byte nanosecond subfield and its 32 byte Blake3 hash
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{WasmiumHashID, ID_LEN};
+    ///
+    /// let ids = (0u32..100)
+    ///     .map(|index| WasmiumHashID::new(blake3::hash(&index.to_le_bytes())))
+    ///     .collect::<Vec<WasmiumHashID>>();
+    ///
+    /// let encoded = WasmiumHashID::delta_encode_batch(&ids);
+    /// assert!(encoded.len() < WasmiumHashID::encoded_len(ids.len()));
+    /// assert_eq!(encoded.len(), ID_LEN + 99 * 37);
+    /// ```
+    pub fn delta_encode_batch(ids: &[WasmiumHashID]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut previous: Option<u64> = None;
+
+        for id in ids {
+            let (seconds, nanos) = split_timestamp(&id.tai_timestamp);
+
+            match previous {
+                None => out.extend_from_slice(&id.build()),
+                Some(previous) => {
+                    let delta = seconds.wrapping_sub(previous) as i64;
+                    write_varint(((delta << 1) ^ (delta >> 63)) as u64, &mut out);
+                    out.extend_from_slice(&nanos);
+                    out.extend_from_slice(&id.blake3hash);
+                }
+            }
+
+            previous = Some(seconds);
+        }
+
+        out
+    }
+
+    /// Decode a batch of `WasmiumHashID`s encoded by `delta_encode_batch`, validating every timestamp.
+    /// A truncated record is reported as `Error::InvalidLength` with the number of bytes left
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let start = Tai64N::now();
+    /// let ids = [5u64, 0, 300, 300, 1]
+    ///     .iter()
+    ///     .map(|seconds| {
+    ///         WasmiumHashID::new_with_timestamp(
+    ///             blake3::hash(&seconds.to_le_bytes()),
+    ///             start + Duration::from_secs(*seconds),
+    ///         )
+    ///     })
+    ///     .collect::<Vec<WasmiumHashID>>();
+    ///
+    /// let encoded = WasmiumHashID::delta_encode_batch(&ids);
+    /// assert_eq!(WasmiumHashID::delta_decode_batch(&encoded), Ok(ids));
+    /// assert_eq!(WasmiumHashID::delta_decode_batch(&[]), Ok(Vec::new()));
+    ///
+    /// assert_eq!(
+    ///     WasmiumHashID::delta_decode_batch(&encoded[..encoded.len() - 1]),
+    ///     Err(Error::InvalidLength(35))
+    /// );
+    /// ```
+    pub fn delta_decode_batch(bytes: &[u8]) -> Result<Vec<WasmiumHashID>, Error> {
+        if bytes.is_empty() {
+            return Ok(Vec::new());
+        }
+        if bytes.len() < ID_LEN {
+            return Err(Error::InvalidLength(bytes.len()));
+        }
+
+        let first = WasmiumHashID::from_slice(&bytes[..ID_LEN])?;
+        let (mut seconds, _) = split_timestamp(&first.tai_timestamp);
+        let mut ids = vec![first];
+        let mut rest = &bytes[ID_LEN..];
+
+        while !rest.is_empty() {
+            let (zigzag, read) = read_varint(rest)?;
+            rest = &rest[read..];
+            if rest.len() < 4 + HASH_LEN {
+                return Err(Error::InvalidLength(rest.len()));
+            }
+
+            let delta = (zigzag >> 1) as i64 ^ -((zigzag & 1) as i64);
+            seconds = seconds.wrapping_add(delta as u64);
+
+            let mut record = [0u8; ID_LEN];
+            record[..8].copy_from_slice(&seconds.to_be_bytes());
+            record[8..].copy_from_slice(&rest[..4 + HASH_LEN]);
+            rest = &rest[4 + HASH_LEN..];
+
+            ids.push(WasmiumHashID::from_slice(&record)?);
+        }
+
+        Ok(ids)
+    }
+}
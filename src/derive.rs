@@ -0,0 +1,323 @@
+use crate::WasmiumHashID;
+
+/// The Blake3 key derivation context domain separating `WasmiumHashID::pair`
+const PAIR_CONTEXT: &str = "wasmium-hash-id request response pair v1";
+
+impl WasmiumHashID {
+    /// Combine two `WasmiumHashID`s into a new one by hashing `a`'s Blake3 hash followed by `b`'s,
+    /// stamped with the current TAI64N timestamp.
+    /// The operation is order sensitive, `combine(a, b)` and `combine(b, a)` differ
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let a = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let b = WasmiumHashID::new(blake3::hash(b"bar"));
+    ///
+    /// let mut concatenated = a.digest_bytes().to_vec();
+    /// concatenated.extend_from_slice(b.digest_bytes());
+    ///
+    /// let combined = WasmiumHashID::combine(&a, &b);
+    /// assert_eq!(combined.digest_bytes(), blake3::hash(&concatenated).as_bytes());
+    /// assert_ne!(
+    ///     combined.digest_bytes(),
+    ///     WasmiumHashID::combine(&b, &a).digest_bytes()
+    /// );
+    /// ```
+    pub fn combine(a: &Self, b: &Self) -> WasmiumHashID {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(a.digest_bytes());
+        hasher.update(b.digest_bytes());
+
+        WasmiumHashID::from_hasher(hasher)
+    }
+
+    /// Combine two `WasmiumHashID`s like `combine`, hashing `a`'s Blake3 hash followed by `b`'s,
+    /// but keep the later of their two TAI64N timestamps instead of stamping the current time
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let timestamp = Tai64N::now();
+    /// let a = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), timestamp);
+    /// let b = WasmiumHashID::new_with_timestamp(
+    ///     blake3::hash(b"bar"),
+    ///     timestamp - Duration::from_secs(60),
+    /// );
+    ///
+    /// let merged = WasmiumHashID::merge_latest(&a, &b);
+    /// assert_eq!(merged.timestamp_bytes(), a.timestamp_bytes());
+    /// assert_eq!(merged.digest_bytes(), WasmiumHashID::combine(&a, &b).digest_bytes());
+    /// assert_eq!(WasmiumHashID::merge_latest(&b, &a).timestamp_bytes(), a.timestamp_bytes());
+    /// ```
+    pub fn merge_latest(a: &Self, b: &Self) -> WasmiumHashID {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(a.digest_bytes());
+        hasher.update(b.digest_bytes());
+
+        let hash_id = WasmiumHashID {
+            tai_timestamp: a.tai_timestamp.max(b.tai_timestamp),
+            blake3hash: *hasher.finalize().as_bytes(),
+        };
+        #[cfg(feature = "tracing")]
+        hash_id.trace("merge_latest");
+
+        hash_id
+    }
+
+    /// Combine a set of `WasmiumHashID`s into a new one regardless of their order,
+    /// by sorting their Blake3 hashes before hashing them together.
+    /// The result is stamped with the current TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let a = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let b = WasmiumHashID::new(blake3::hash(b"bar"));
+    /// let c = WasmiumHashID::new(blake3::hash(b"baz"));
+    ///
+    /// let combined = WasmiumHashID::combine_unordered(&[&a, &b, &c]);
+    /// assert_eq!(
+    ///     combined.digest_bytes(),
+    ///     WasmiumHashID::combine_unordered(&[&c, &a, &b]).digest_bytes()
+    /// );
+    /// assert_eq!(
+    ///     combined.digest_bytes(),
+    ///     WasmiumHashID::combine_unordered(&[&b, &c, &a]).digest_bytes()
+    /// );
+    /// ```
+    pub fn combine_unordered(ids: &[&Self]) -> WasmiumHashID {
+        let mut digests = ids.iter().map(|id| id.digest_bytes()).collect::<Vec<_>>();
+        digests.sort_unstable();
+
+        let mut hasher = blake3::Hasher::new();
+        digests.into_iter().for_each(|digest| {
+            hasher.update(digest);
+        });
+
+        WasmiumHashID::from_hasher(hasher)
+    }
+
+    /// Generate a new `WasmiumHashID` addressing `content` the way Git addresses blobs,
+    /// hashing the `blob <len>\0` header followed by the content.
+    /// The hash is Blake3, not the SHA-1 Git uses, so it does not match Git object IDs
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::from_git_blob(b"foo");
+    ///
+    /// assert_eq!(hash_id.digest_bytes(), blake3::hash(b"blob 3\0foo").as_bytes());
+    /// assert_ne!(hash_id.digest_bytes(), blake3::hash(b"foo").as_bytes());
+    /// ```
+    pub fn from_git_blob(content: &[u8]) -> WasmiumHashID {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(format!("blob {}\0", content.len()).as_bytes());
+        hasher.update(content);
+
+        WasmiumHashID::from_hasher(hasher)
+    }
+
+    /// Derive a namespaced `WasmiumHashID` by hashing this ID's Blake3 hash followed by `salt`,
+    /// stamped with the current TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    ///
+    /// let first = hash_id.salted(b"users");
+    /// let second = hash_id.salted(b"groups");
+    ///
+    /// assert_ne!(first.digest_bytes(), second.digest_bytes());
+    /// assert_eq!(first.digest_bytes(), hash_id.salted(b"users").digest_bytes());
+    /// ```
+    pub fn salted(&self, salt: &[u8]) -> WasmiumHashID {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(self.digest_bytes());
+        hasher.update(salt);
+
+        WasmiumHashID::from_hasher(hasher)
+    }
+
+    /// Recompute a keyed `WasmiumHashID` under `new_key` from the original `message`,
+    /// keeping the TAI64N timestamp of `original` so keys can be rotated without re-stamping time
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{WasmiumHashID, WasmiumHashIDKeyedHasher};
+    ///
+    /// let original = WasmiumHashIDKeyedHasher::new(&[1u8; 32]).update(b"foo").finalize();
+    /// let rekeyed = WasmiumHashID::rekey(&original, b"foo", &[2u8; 32]);
+    ///
+    /// assert_eq!(rekeyed.timestamp_bytes(), original.timestamp_bytes());
+    /// assert_ne!(rekeyed.digest_bytes(), original.digest_bytes());
+    /// assert_eq!(rekeyed.digest_bytes(), blake3::keyed_hash(&[2u8; 32], b"foo").as_bytes());
+    /// ```
+    pub fn rekey(original: &WasmiumHashID, message: &[u8], new_key: &[u8; 32]) -> WasmiumHashID {
+        let hash_id = WasmiumHashID {
+            tai_timestamp: original.tai_timestamp,
+            blake3hash: *blake3::keyed_hash(new_key, message).as_bytes(),
+        };
+        #[cfg(feature = "tracing")]
+        hash_id.trace("rekey");
+
+        hash_id
+    }
+
+    /// Generate a `WasmiumHashID` from the Blake3 hash of `namespace || 0x00 || message`,
+    /// so the same message in different namespaces gives different IDs.
+    /// The separator keeps the split unambiguous as long as `namespace` contains no NUL byte
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let users = WasmiumHashID::namespaced("users", b"foo");
+    /// let groups = WasmiumHashID::namespaced("groups", b"foo");
+    /// assert_ne!(users.digest_bytes(), groups.digest_bytes());
+    ///
+    /// assert_ne!(
+    ///     WasmiumHashID::namespaced("ab", b"c").digest_bytes(),
+    ///     WasmiumHashID::namespaced("a", b"bc").digest_bytes()
+    /// );
+    /// assert_eq!(users.digest_bytes(), blake3::hash(b"users\0foo").as_bytes());
+    /// ```
+    pub fn namespaced(namespace: &str, message: &[u8]) -> WasmiumHashID {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(namespace.as_bytes());
+        hasher.update(&[0x00]);
+        hasher.update(message);
+
+        WasmiumHashID::from_hasher(hasher)
+    }
+
+    /// Generate a correlation `WasmiumHashID` for a request and its response. Both are hashed
+    /// under a fixed Blake3 key derivation context, with the request length as a big endian
+    /// `u64` separating them, so no other request and response split collides
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let pair = WasmiumHashID::pair(b"GET /foo", b"200 OK");
+    ///
+    /// assert_eq!(pair.digest_bytes(), WasmiumHashID::pair(b"GET /foo", b"200 OK").digest_bytes());
+    /// assert_ne!(pair.digest_bytes(), WasmiumHashID::pair(b"200 OK", b"GET /foo").digest_bytes());
+    /// assert_ne!(
+    ///     WasmiumHashID::pair(b"ab", b"c").digest_bytes(),
+    ///     WasmiumHashID::pair(b"a", b"bc").digest_bytes()
+    /// );
+    /// ```
+    pub fn pair(request: &[u8], response: &[u8]) -> WasmiumHashID {
+        let mut hasher = blake3::Hasher::new_derive_key(PAIR_CONTEXT);
+        hasher.update(&(request.len() as u64).to_be_bytes());
+        hasher.update(request);
+        hasher.update(response);
+
+        WasmiumHashID::from_hasher(hasher)
+    }
+
+    /// Generate a `WasmiumHashID` for a directory from the names and Blake3 hashes of its entries,
+    /// stamped with the current TAI64N timestamp. The entries are sorted by name and each is
+    /// hashed as the name length as a big endian `u64`, the name and the child hash,
+    /// so the root does not depend on the input order
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let entries = vec![
+    ///     ("src".to_string(), blake3::hash(b"foo")),
+    ///     ("Cargo.toml".to_string(), blake3::hash(b"bar")),
+    ///     ("README.md".to_string(), blake3::hash(b"baz")),
+    /// ];
+    /// let root = WasmiumHashID::merkle_root(&entries);
+    ///
+    /// let mut reordered = entries.clone();
+    /// reordered.reverse();
+    /// assert_eq!(root.digest_bytes(), WasmiumHashID::merkle_root(&reordered).digest_bytes());
+    ///
+    /// let mut changed = entries.clone();
+    /// changed[0].1 = blake3::hash(b"qux");
+    /// assert_ne!(root.digest_bytes(), WasmiumHashID::merkle_root(&changed).digest_bytes());
+    /// ```
+    pub fn merkle_root(entries: &[(String, blake3::Hash)]) -> WasmiumHashID {
+        let mut sorted = entries.iter().collect::<Vec<_>>();
+        sorted.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut hasher = blake3::Hasher::new();
+        sorted.into_iter().for_each(|(name, child)| {
+            hasher.update(&(name.len() as u64).to_be_bytes());
+            hasher.update(name.as_bytes());
+            hasher.update(child.as_bytes());
+        });
+
+        WasmiumHashID::from_hasher(hasher)
+    }
+
+    /// Generate a `WasmiumHashID` from text that is stable under cosmetic whitespace changes,
+    /// stamped with the current TAI64N timestamp. Before hashing, `\r\n` and lone `\r` line
+    /// endings become `\n` and trailing whitespace as defined by `char::is_whitespace` is
+    /// stripped from every line. Leading whitespace, blank lines and a final line ending are kept
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let unix = WasmiumHashID::from_normalized_text("foo\nbar\n");
+    /// let windows = WasmiumHashID::from_normalized_text("foo  \r\nbar\t\r\n");
+    ///
+    /// assert_eq!(unix.digest_bytes(), windows.digest_bytes());
+    /// assert_eq!(unix.digest_bytes(), blake3::hash(b"foo\nbar\n").as_bytes());
+    /// assert_ne!(
+    ///     unix.digest_bytes(),
+    ///     WasmiumHashID::from_normalized_text("  foo\nbar\n").digest_bytes()
+    /// );
+    /// ```
+    pub fn from_normalized_text(text: &str) -> WasmiumHashID {
+        let normalized = text.replace("\r\n", "\n").replace('\r', "\n");
+
+        let mut hasher = blake3::Hasher::new();
+        for (index, line) in normalized.split('\n').enumerate() {
+            if index > 0 {
+                hasher.update(b"\n");
+            }
+            hasher.update(line.trim_end().as_bytes());
+        }
+
+        WasmiumHashID::from_hasher(hasher)
+    }
+
+    /// Generate a `WasmiumHashID` from a per context MAC of `message`, stamped with the current
+    /// TAI64N timestamp. A subkey is first derived with `blake3::derive_key(context, key)` and
+    /// the message is then hashed with `blake3::keyed_hash` under that subkey
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let key = [7u8; 32];
+    /// let hash_id = WasmiumHashID::new_keyed_context("app 2024 sessions", &key, b"foo");
+    ///
+    /// let subkey = blake3::derive_key("app 2024 sessions", &key);
+    /// assert_eq!(hash_id.digest_bytes(), blake3::keyed_hash(&subkey, b"foo").as_bytes());
+    ///
+    /// let other_context = WasmiumHashID::new_keyed_context("app 2024 uploads", &key, b"foo");
+    /// let other_key = WasmiumHashID::new_keyed_context("app 2024 sessions", &[8u8; 32], b"foo");
+    /// assert_ne!(hash_id.digest_bytes(), other_context.digest_bytes());
+    /// assert_ne!(hash_id.digest_bytes(), other_key.digest_bytes());
+    /// ```
+    pub fn new_keyed_context(context: &str, key: &[u8; 32], message: &[u8]) -> WasmiumHashID {
+        let subkey = blake3::derive_key(context, key);
+
+        WasmiumHashID::new(blake3::keyed_hash(&subkey, message))
+    }
+}
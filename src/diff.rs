@@ -0,0 +1,66 @@
+use crate::WasmiumHashID;
+
+/// The components that differ between two `WasmiumHashID`s, returned by `WasmiumHashID::diff`
+///
+/// #### Usage
+/// ```rust
+/// use wasmium_hash_id::{IdDiff, WasmiumHashID};
+///
+/// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+///
+/// assert_eq!(hash_id.diff(&hash_id), IdDiff::Same);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdDiff {
+    /// Both the TAI64N timestamp and the Blake3 hash are equal
+    Same,
+    /// Only the TAI64N timestamp differs
+    Timestamp,
+    /// Only the Blake3 hash differs
+    Digest,
+    /// Both the TAI64N timestamp and the Blake3 hash differ
+    Both,
+}
+
+impl WasmiumHashID {
+    /// Compare the TAI64N timestamp and the Blake3 hash of two `WasmiumHashID`s separately
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::{IdDiff, WasmiumHashID};
+    ///
+    /// let timestamp = Tai64N::now();
+    /// let later = timestamp + Duration::from_secs(1);
+    /// let hash_id = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), timestamp);
+    ///
+    /// assert_eq!(
+    ///     hash_id.diff(&WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), timestamp)),
+    ///     IdDiff::Same
+    /// );
+    /// assert_eq!(
+    ///     hash_id.diff(&WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), later)),
+    ///     IdDiff::Timestamp
+    /// );
+    /// assert_eq!(
+    ///     hash_id.diff(&WasmiumHashID::new_with_timestamp(blake3::hash(b"bar"), timestamp)),
+    ///     IdDiff::Digest
+    /// );
+    /// assert_eq!(
+    ///     hash_id.diff(&WasmiumHashID::new_with_timestamp(blake3::hash(b"bar"), later)),
+    ///     IdDiff::Both
+    /// );
+    /// ```
+    pub fn diff(&self, other: &Self) -> IdDiff {
+        match (
+            self.tai_timestamp == other.tai_timestamp,
+            self.blake3hash == other.blake3hash,
+        ) {
+            (true, true) => IdDiff::Same,
+            (false, true) => IdDiff::Timestamp,
+            (true, false) => IdDiff::Digest,
+            (false, false) => IdDiff::Both,
+        }
+    }
+}
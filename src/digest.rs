@@ -0,0 +1,107 @@
+use crate::WasmiumHashID;
+
+/// A function producing the Blake3 hash a `WasmiumHashID` is generated from,
+/// letting the plain, keyed and derive key modes of Blake3 share one constructor.
+/// Any `Fn(&[u8]) -> blake3::Hash` closure is also a `DigestFn`
+///
+/// #### Usage
+/// ```rust
+/// use wasmium_hash_id::{DigestFn, WasmiumHashID};
+///
+/// struct Reversed;
+///
+/// impl DigestFn for Reversed {
+///     fn digest(&self, message: &[u8]) -> blake3::Hash {
+///         blake3::hash(&message.iter().rev().copied().collect::<Vec<u8>>())
+///     }
+/// }
+///
+/// let hash_id = WasmiumHashID::with_digest_fn(Reversed, b"foo");
+/// assert_eq!(hash_id.digest_bytes(), blake3::hash(b"oof").as_bytes());
+/// ```
+pub trait DigestFn {
+    /// Hash `message`
+    fn digest(&self, message: &[u8]) -> blake3::Hash;
+}
+
+impl<F: Fn(&[u8]) -> blake3::Hash> DigestFn for F {
+    fn digest(&self, message: &[u8]) -> blake3::Hash {
+        self(message)
+    }
+}
+
+/// The plain Blake3 hash, `blake3::hash`
+///
+/// #### Usage
+/// ```rust
+/// use wasmium_hash_id::{Blake3, WasmiumHashID};
+///
+/// let hash_id = WasmiumHashID::with_digest_fn(Blake3, b"foo");
+/// assert_eq!(hash_id.digest_bytes(), blake3::hash(b"foo").as_bytes());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Blake3;
+
+impl DigestFn for Blake3 {
+    fn digest(&self, message: &[u8]) -> blake3::Hash {
+        blake3::hash(message)
+    }
+}
+
+/// The keyed Blake3 hash with a 32 byte key, `blake3::keyed_hash`
+///
+/// #### Usage
+/// ```rust
+/// use wasmium_hash_id::{Blake3Keyed, WasmiumHashID};
+///
+/// let key = [7u8; 32];
+/// let hash_id = WasmiumHashID::with_digest_fn(Blake3Keyed(key), b"foo");
+/// assert_eq!(hash_id.digest_bytes(), blake3::keyed_hash(&key, b"foo").as_bytes());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Blake3Keyed(pub [u8; 32]);
+
+impl DigestFn for Blake3Keyed {
+    fn digest(&self, message: &[u8]) -> blake3::Hash {
+        blake3::keyed_hash(&self.0, message)
+    }
+}
+
+/// The Blake3 key derivation mode with a context string, `blake3::derive_key`
+///
+/// #### Usage
+/// ```rust
+/// use wasmium_hash_id::{Blake3DeriveKey, WasmiumHashID};
+///
+/// let context = "wasmium 2022-05-01 session keys";
+/// let hash_id = WasmiumHashID::with_digest_fn(Blake3DeriveKey(context), b"foo");
+/// assert_eq!(hash_id.digest_bytes(), &blake3::derive_key(context, b"foo"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Blake3DeriveKey<'a>(pub &'a str);
+
+impl DigestFn for Blake3DeriveKey<'_> {
+    fn digest(&self, message: &[u8]) -> blake3::Hash {
+        blake3::derive_key(self.0, message).into()
+    }
+}
+
+impl WasmiumHashID {
+    /// Generate a new `WasmiumHashID` by hashing `message` with `f`,
+    /// stamped with the current TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Blake3, Blake3Keyed, WasmiumHashID};
+    ///
+    /// let plain = WasmiumHashID::with_digest_fn(Blake3, b"foo");
+    /// let keyed = WasmiumHashID::with_digest_fn(Blake3Keyed([7u8; 32]), b"foo");
+    /// let closure = WasmiumHashID::with_digest_fn(|message: &[u8]| blake3::hash(message), b"foo");
+    ///
+    /// assert_ne!(plain.digest_bytes(), keyed.digest_bytes());
+    /// assert_eq!(plain.digest_bytes(), closure.digest_bytes());
+    /// ```
+    pub fn with_digest_fn<F: DigestFn>(f: F, message: &[u8]) -> WasmiumHashID {
+        WasmiumHashID::new(f.digest(message))
+    }
+}
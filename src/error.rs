@@ -0,0 +1,78 @@
+use core::fmt;
+
+use crate::HASH_LEN;
+
+/// Errors returned when decoding or validating a `WasmiumHashID`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input does not have a valid length, holds the length that was found
+    InvalidLength(usize),
+    /// The input is not valid for the encoding it was decoded from
+    InvalidEncoding,
+    /// The padding bytes of a padded `WasmiumHashID` are not all zero
+    InvalidPadding,
+    /// The TAI64N timestamp could not be decoded
+    Timestamp(tai64::Error),
+    /// The timestamp is earlier than the epoch it is measured from
+    BeforeEpoch,
+    /// A duration of zero was given where a positive duration is required
+    ZeroDuration,
+    /// The Blake3 hash is not the one that was expected
+    DigestMismatch {
+        /// The Blake3 hash that was expected
+        expected: [u8; HASH_LEN],
+        /// The Blake3 hash that was found
+        found: [u8; HASH_LEN],
+    },
+    /// The checksum embedded in the encoding does not match the decoded bytes
+    InvalidChecksum,
+    /// The timestamp is outside of the allowed time range
+    OutOfRange,
+    /// The Blake3 hash is not in the set of allowed hashes
+    DigestNotAllowed,
+    /// The shard or ring slot count is zero or larger than the supported maximum, holds the count that was given
+    InvalidShardCount(u64),
+    /// Reading or writing the underlying I/O source failed
+    Io(std::io::ErrorKind),
+    /// A value could not be serialized or deserialized
+    Serialization(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength(length) => write!(f, "invalid length of {} bytes", length),
+            Error::InvalidEncoding => write!(f, "invalid encoding"),
+            Error::InvalidPadding => write!(f, "padding bytes are not zero"),
+            Error::Timestamp(error) => write!(f, "invalid TAI64N timestamp: {}", error),
+            Error::BeforeEpoch => write!(f, "timestamp is before the epoch"),
+            Error::ZeroDuration => write!(f, "duration must not be zero"),
+            Error::DigestMismatch { expected, found } => write!(
+                f,
+                "expected Blake3 hash {} but found {}",
+                blake3::Hash::from(*expected).to_hex(),
+                blake3::Hash::from(*found).to_hex()
+            ),
+            Error::InvalidChecksum => write!(f, "checksum does not match"),
+            Error::OutOfRange => write!(f, "timestamp is outside of the allowed range"),
+            Error::DigestNotAllowed => write!(f, "Blake3 hash is not allowed"),
+            Error::InvalidShardCount(shards) => write!(f, "invalid shard count of {}", shards),
+            Error::Io(kind) => write!(f, "I/O error: {}", kind),
+            Error::Serialization(error) => write!(f, "serialization failed: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error.kind())
+    }
+}
+
+impl From<tai64::Error> for Error {
+    fn from(error: tai64::Error) -> Self {
+        Error::Timestamp(error)
+    }
+}
@@ -0,0 +1,35 @@
+use generic_array::{typenum::U44, GenericArray};
+
+use crate::{Error, WasmiumHashID};
+
+impl WasmiumHashID {
+    /// Build the `WasmiumHashID` into a `GenericArray` for use with RustCrypto traits
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let array = hash_id.to_generic_array();
+    ///
+    /// assert_eq!(array.as_slice(), &hash_id.build());
+    /// ```
+    pub fn to_generic_array(&self) -> GenericArray<u8, U44> {
+        GenericArray::from(self.build())
+    }
+
+    /// Restore a `WasmiumHashID` from a `GenericArray`, validating the TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let array = hash_id.to_generic_array();
+    ///
+    /// assert_eq!(WasmiumHashID::from_generic_array(&array), Ok(hash_id));
+    /// ```
+    pub fn from_generic_array(array: &GenericArray<u8, U44>) -> Result<WasmiumHashID, Error> {
+        WasmiumHashID::from_slice(array.as_slice())
+    }
+}
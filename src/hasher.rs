@@ -0,0 +1,157 @@
+use std::io::{self, Write};
+
+use crate::WasmiumHashID;
+
+/// Incrementally compute a keyed Blake3 hash over a streamed payload, then stamp it into a `WasmiumHashID`
+///
+/// #### Usage
+/// ```rust
+/// use wasmium_hash_id::{WasmiumHashID, WasmiumHashIDKeyedHasher};
+///
+/// let key = [7u8; 32];
+/// let mut hasher = WasmiumHashIDKeyedHasher::new(&key);
+/// hasher.update(b"foo").update(b"bar");
+///
+/// let hash_id = hasher.finalize().build();
+/// assert_eq!(&hash_id[12..], blake3::keyed_hash(&key, b"foobar").as_bytes());
+/// ```
+#[derive(Debug, Clone)]
+pub struct WasmiumHashIDKeyedHasher {
+    hasher: blake3::Hasher,
+}
+
+impl WasmiumHashIDKeyedHasher {
+    /// Create a new keyed hasher from a 32 byte key
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashIDKeyedHasher;
+    ///
+    /// let hasher = WasmiumHashIDKeyedHasher::new(&[7u8; 32]);
+    /// ```
+    pub fn new(key: &[u8; 32]) -> Self {
+        WasmiumHashIDKeyedHasher {
+            hasher: blake3::Hasher::new_keyed(key),
+        }
+    }
+
+    /// Add a chunk of the payload to the hash
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashIDKeyedHasher;
+    ///
+    /// let key = [7u8; 32];
+    /// let mut hasher = WasmiumHashIDKeyedHasher::new(&key);
+    /// for chunk in [b"foo".as_slice(), b"bar", b"baz"] {
+    ///     hasher.update(chunk);
+    /// }
+    ///
+    /// let hash_id = hasher.finalize().build();
+    /// assert_eq!(&hash_id[12..], blake3::keyed_hash(&key, b"foobarbaz").as_bytes());
+    /// ```
+    pub fn update(&mut self, input: &[u8]) -> &mut Self {
+        self.hasher.update(input);
+
+        self
+    }
+
+    /// Finalize the keyed hash into a `WasmiumHashID` stamped with the current TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashIDKeyedHasher;
+    ///
+    /// let mut hasher = WasmiumHashIDKeyedHasher::new(&[7u8; 32]);
+    /// hasher.update(b"foo");
+    ///
+    /// let hash_id = hasher.finalize();
+    /// ```
+    pub fn finalize(&self) -> WasmiumHashID {
+        WasmiumHashID::new(self.hasher.finalize())
+    }
+}
+
+/// Hash everything written through it while forwarding the bytes to an inner writer,
+/// so a pipeline can hash on write without a second pass over the data
+///
+/// #### Usage
+/// ```rust
+/// use std::io::Write;
+/// use wasmium_hash_id::TeeHasher;
+///
+/// let mut tee = TeeHasher::new(Vec::new());
+/// tee.write_all(b"foo").unwrap();
+/// tee.write_all(b"bar").unwrap();
+///
+/// let (hash_id, forwarded) = tee.finish();
+/// assert_eq!(forwarded, b"foobar");
+/// assert_eq!(hash_id.digest_bytes(), blake3::hash(b"foobar").as_bytes());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TeeHasher<W: Write> {
+    inner: W,
+    hasher: blake3::Hasher,
+}
+
+impl<W: Write> TeeHasher<W> {
+    /// Wrap `inner` with an unkeyed Blake3 hasher
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::TeeHasher;
+    ///
+    /// let tee = TeeHasher::new(std::io::sink());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        TeeHasher {
+            inner,
+            hasher: blake3::Hasher::new(),
+        }
+    }
+
+    /// Finalize the hash of the bytes written so far into a `WasmiumHashID` stamped with
+    /// the current TAI64N timestamp and return it with the inner writer
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::io::Write;
+    /// use wasmium_hash_id::TeeHasher;
+    ///
+    /// let mut tee = TeeHasher::new(Vec::new());
+    /// tee.write_all(b"foo").unwrap();
+    ///
+    /// let (hash_id, forwarded) = tee.finish();
+    /// assert_eq!(hash_id.digest_bytes(), blake3::hash(&forwarded).as_bytes());
+    /// ```
+    pub fn finish(self) -> (WasmiumHashID, W) {
+        (WasmiumHashID::new(self.hasher.finalize()), self.inner)
+    }
+}
+
+impl<W: Write> Write for TeeHasher<W> {
+    /// Write to the inner writer, then hash only the bytes it accepted
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::io::Write;
+    /// use wasmium_hash_id::TeeHasher;
+    ///
+    /// let mut buffer = [0u8; 4];
+    /// let mut tee = TeeHasher::new(&mut buffer[..]);
+    ///
+    /// assert_eq!(tee.write(b"foobar").unwrap(), 4);
+    /// let (hash_id, _) = tee.finish();
+    /// assert_eq!(hash_id.digest_bytes(), blake3::hash(b"foob").as_bytes());
+    /// ```
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
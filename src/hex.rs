@@ -0,0 +1,384 @@
+use crate::{Error, WasmiumHashID, HASH_LEN, ID_LEN};
+
+/// The lower case hex alphabet
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+/// Decode a single ASCII hex character, accepting both lower and upper case
+const fn decode_nibble(character: u8) -> Result<u8, Error> {
+    match character {
+        b'0'..=b'9' => Ok(character - b'0'),
+        b'a'..=b'f' => Ok(character - b'a' + 10),
+        b'A'..=b'F' => Ok(character - b'A' + 10),
+        _ => Err(Error::InvalidEncoding),
+    }
+}
+
+/// Encode `input` as lower case hex into `out` without allocating,
+/// `out` must be exactly twice the length of `input`
+pub(crate) fn encode_into(input: &[u8], out: &mut [u8]) {
+    for (byte, pair) in input.iter().zip(out.chunks_exact_mut(2)) {
+        pair[0] = HEX_CHARS[(byte >> 4) as usize];
+        pair[1] = HEX_CHARS[(byte & 0x0f) as usize];
+    }
+}
+
+/// Decode the hex string `input` into `out` without allocating,
+/// `input` must be exactly twice the length of `out`
+pub(crate) fn decode_into(input: &[u8], out: &mut [u8]) -> Result<(), Error> {
+    if input.len() != out.len() * 2 {
+        return Err(Error::InvalidLength(input.len()));
+    }
+
+    for (byte, pair) in out.iter_mut().zip(input.chunks_exact(2)) {
+        *byte = (decode_nibble(pair[0])? << 4) | decode_nibble(pair[1])?;
+    }
+
+    Ok(())
+}
+
+impl WasmiumHashID {
+    /// Parse a `WasmiumHashID` from 88 hex characters without allocating,
+    /// validating the TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let hex = hash_id
+    ///     .build()
+    ///     .iter()
+    ///     .map(|byte| format!("{:02x}", byte))
+    ///     .collect::<String>();
+    ///
+    /// assert_eq!(WasmiumHashID::parse_hex_into(&hex), Ok(hash_id));
+    /// assert_eq!(
+    ///     WasmiumHashID::parse_hex_into(&hex[..87]),
+    ///     Err(Error::InvalidLength(87))
+    /// );
+    /// assert_eq!(
+    ///     WasmiumHashID::parse_hex_into(&format!("g{}", &hex[1..])),
+    ///     Err(Error::InvalidEncoding)
+    /// );
+    /// ```
+    pub fn parse_hex_into(s: &str) -> Result<WasmiumHashID, Error> {
+        let mut bytes = [0u8; ID_LEN];
+        decode_into(s.as_bytes(), &mut bytes)?;
+
+        WasmiumHashID::from_slice(&bytes)
+    }
+
+    /// Check whether `s` is 88 hex characters encoding a `WasmiumHashID` with a valid
+    /// TAI64N timestamp, without allocating
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hex = WasmiumHashID::new(blake3::hash(b"foo")).to_hex();
+    /// assert!(WasmiumHashID::is_valid_hex(&hex));
+    /// assert!(WasmiumHashID::is_valid_hex(&hex.to_uppercase()));
+    ///
+    /// assert!(!WasmiumHashID::is_valid_hex(&hex[..86]));
+    /// assert!(!WasmiumHashID::is_valid_hex(&format!("zz{}", &hex[2..])));
+    ///
+    /// // Nanoseconds of 0xffffffff
+    /// let invalid_timestamp = format!("{}ffffffff{}", &hex[..16], &hex[24..]);
+    /// assert!(!WasmiumHashID::is_valid_hex(&invalid_timestamp));
+    /// ```
+    pub fn is_valid_hex(s: &str) -> bool {
+        WasmiumHashID::parse_hex_into(s).is_ok()
+    }
+
+    /// Find the `WasmiumHashID`s written as hex in unstructured text such as logs.
+    /// Only maximal runs of exactly 88 hex characters are considered, so longer hex blobs
+    /// are not split into candidates, and runs whose TAI64N timestamp is invalid are skipped
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let hex = hash_id.to_hex();
+    /// let invalid_timestamp = format!("{}ffffffff{}", &hex[..16], &hex[24..]);
+    ///
+    /// let line = format!(
+    ///     "INFO id={} trace=deadbeef noise={} blob={}00",
+    ///     hex, invalid_timestamp, hex
+    /// );
+    ///
+    /// assert_eq!(WasmiumHashID::scan_text(&line), vec![hash_id]);
+    /// assert!(WasmiumHashID::scan_text("no ids here").is_empty());
+    /// ```
+    pub fn scan_text(text: &str) -> Vec<WasmiumHashID> {
+        text.split(|character: char| !character.is_ascii_hexdigit())
+            .filter(|run| run.len() == ID_LEN * 2)
+            .filter_map(|run| WasmiumHashID::parse_hex_into(run).ok())
+            .collect()
+    }
+
+    /// Encode the `WasmiumHashID` as 88 lower case hex characters without allocating
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let hex = hash_id.to_hex_array();
+    ///
+    /// assert_eq!(WasmiumHashID::parse_hex_into(core::str::from_utf8(&hex).unwrap()), Ok(hash_id));
+    /// ```
+    pub fn to_hex_array(&self) -> [u8; ID_LEN * 2] {
+        let mut hex = [0u8; ID_LEN * 2];
+        encode_into(&self.build(), &mut hex);
+
+        hex
+    }
+
+    /// Encode the `WasmiumHashID` as 88 lower case hex characters into the start of `out`
+    /// without allocating, returning the number of bytes written
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use tai64::{Tai64, Tai64N};
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new_with_timestamp([0xffu8; 32].into(), Tai64N(Tai64(0), 0));
+    /// let mut out = [0u8; 100];
+    /// let written = hash_id.encode_hex_into(&mut out).unwrap();
+    ///
+    /// assert_eq!(written, 88);
+    /// assert_eq!(&out[..24], "0".repeat(24).as_bytes());
+    /// assert_eq!(&out[24..88], "f".repeat(64).as_bytes());
+    /// assert_eq!(
+    ///     WasmiumHashID::parse_hex_into(core::str::from_utf8(&out[..written]).unwrap()),
+    ///     Ok(hash_id.clone())
+    /// );
+    ///
+    /// assert_eq!(hash_id.encode_hex_into(&mut [0u8; 87]), Err(Error::InvalidLength(87)));
+    /// ```
+    pub fn encode_hex_into(&self, out: &mut [u8]) -> Result<usize, Error> {
+        if out.len() < ID_LEN * 2 {
+            return Err(Error::InvalidLength(out.len()));
+        }
+
+        encode_into(&self.build(), &mut out[..ID_LEN * 2]);
+
+        Ok(ID_LEN * 2)
+    }
+
+    /// Encode the `WasmiumHashID` as a `String` of 88 lower case hex characters
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let hex = hash_id.to_hex();
+    ///
+    /// assert_eq!(hex.len(), 88);
+    /// assert_eq!(WasmiumHashID::parse_hex_into(&hex), Ok(hash_id));
+    /// ```
+    pub fn to_hex(&self) -> String {
+        self.to_hex_array()
+            .iter()
+            .map(|character| *character as char)
+            .collect()
+    }
+
+    /// Encode the `WasmiumHashID` as a fixed width key of 88 lower case hex characters whose
+    /// lexical order matches chronological order. The big-endian TAI64N timestamp leads and
+    /// every byte is zero padded to 2 characters, so sorting the strings sorts the IDs by
+    /// time with the Blake3 hash as the tie breaker, the same as the default `Ord`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let start = Tai64N::now();
+    /// let mut ids = [30u64, 1, 4_000_000_000, 0, 256]
+    ///     .iter()
+    ///     .map(|seconds| {
+    ///         WasmiumHashID::new_with_timestamp(
+    ///             blake3::hash(&seconds.to_le_bytes()),
+    ///             start + Duration::from_secs(*seconds),
+    ///         )
+    ///     })
+    ///     .collect::<Vec<WasmiumHashID>>();
+    ///
+    /// let mut keys = ids.iter().map(WasmiumHashID::sortable_string).collect::<Vec<String>>();
+    /// keys.sort();
+    /// ids.sort_by_key(|id| WasmiumHashID::get_timestamp(id.build()).unwrap());
+    ///
+    /// assert_eq!(keys, ids.iter().map(WasmiumHashID::sortable_string).collect::<Vec<String>>());
+    /// ```
+    pub fn sortable_string(&self) -> String {
+        self.to_hex()
+    }
+
+    /// Encode only the Blake3 hash as 64 lower case hex characters without allocating
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let blake3_hash = blake3::hash(b"foo");
+    /// let hex = WasmiumHashID::new(blake3_hash).digest_hex_array();
+    ///
+    /// assert_eq!(&hex[..], blake3_hash.to_hex().as_bytes());
+    /// ```
+    pub fn digest_hex_array(&self) -> [u8; HASH_LEN * 2] {
+        let mut hex = [0u8; HASH_LEN * 2];
+        encode_into(&self.blake3hash, &mut hex);
+
+        hex
+    }
+
+    /// Get the first 8 hex characters of the Blake3 hash, like a Git short hash.
+    /// This is only meant for display and cannot be turned back into a `WasmiumHashID`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let blake3_hash = blake3::hash(b"foo");
+    /// let short = WasmiumHashID::new(blake3_hash).short();
+    ///
+    /// assert_eq!(short.len(), 8);
+    /// assert!(blake3_hash.to_hex().starts_with(&short));
+    /// ```
+    pub fn short(&self) -> String {
+        self.short_with_len(8)
+    }
+
+    /// Get the first `len` hex characters of the Blake3 hash, `len` is clamped to 64 characters.
+    /// This is only meant for display and cannot be turned back into a `WasmiumHashID`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let blake3_hash = blake3::hash(b"foo");
+    /// let hash_id = WasmiumHashID::new(blake3_hash);
+    ///
+    /// assert_eq!(hash_id.short_with_len(12), &blake3_hash.to_hex()[..12]);
+    /// assert_eq!(hash_id.short_with_len(100), blake3_hash.to_hex().as_str());
+    /// ```
+    pub fn short_with_len(&self, len: usize) -> String {
+        self.digest_hex_array()[..len.min(HASH_LEN * 2)]
+            .iter()
+            .map(|character| *character as char)
+            .collect()
+    }
+
+    /// Get the shortest prefix of the Blake3 hash hex that no other ID in `others` shares,
+    /// like abbreviated Git hashes. IDs in `others` with the same Blake3 hash are ignored
+    /// since no prefix can tell them apart
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let mut digest = [0xabu8; 32];
+    /// let hash_id = WasmiumHashID::new(digest.into());
+    ///
+    /// digest[2] = 0xac;
+    /// let close = WasmiumHashID::new(digest.into());
+    /// let far = WasmiumHashID::new([0x12u8; 32].into());
+    ///
+    /// assert_eq!(hash_id.shortest_unique_prefix(&[far.clone()]), "a");
+    /// assert_eq!(hash_id.shortest_unique_prefix(&[far, close, hash_id.clone()]), "ababab");
+    /// assert_eq!(hash_id.shortest_unique_prefix(&[]), "a");
+    /// ```
+    pub fn shortest_unique_prefix(&self, others: &[WasmiumHashID]) -> String {
+        let hex = self.digest_hex_array();
+
+        let len = others
+            .iter()
+            .filter(|other| other.blake3hash != self.blake3hash)
+            .map(|other| {
+                let other_hex = other.digest_hex_array();
+                hex.iter()
+                    .zip(other_hex.iter())
+                    .take_while(|(a, b)| a == b)
+                    .count()
+                    + 1
+            })
+            .max()
+            .unwrap_or(1);
+
+        self.short_with_len(len)
+    }
+
+    /// Generate a new `WasmiumHashID` from a Blake3 hash encoded as 64 hex characters,
+    /// stamped with the current TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let blake3_hash = blake3::hash(b"foo");
+    /// let hash_id = WasmiumHashID::from_digest_hex(&blake3_hash.to_hex()).unwrap();
+    ///
+    /// assert_eq!(hash_id.build()[12..], *blake3_hash.as_bytes());
+    /// assert_eq!(
+    ///     WasmiumHashID::from_digest_hex(&blake3_hash.to_hex()[..62]),
+    ///     Err(Error::InvalidLength(62))
+    /// );
+    /// ```
+    pub fn from_digest_hex(hex: &str) -> Result<WasmiumHashID, Error> {
+        let mut digest = [0u8; HASH_LEN];
+        decode_into(hex.as_bytes(), &mut digest)?;
+
+        Ok(WasmiumHashID::new(digest.into()))
+    }
+
+    /// Join the hex encodings of a batch of `WasmiumHashID`s with `sep`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let ids = [
+    ///     WasmiumHashID::new(blake3::hash(b"foo")),
+    ///     WasmiumHashID::new(blake3::hash(b"bar")),
+    ///     WasmiumHashID::new(blake3::hash(b"baz")),
+    /// ];
+    ///
+    /// let joined = WasmiumHashID::join_hex(&ids, ",");
+    /// assert_eq!(joined.len(), 3 * 88 + 2);
+    /// assert_eq!(WasmiumHashID::parse_joined_hex(&joined, ","), Ok(ids.to_vec()));
+    /// ```
+    pub fn join_hex(ids: &[WasmiumHashID], sep: &str) -> String {
+        ids.iter()
+            .map(WasmiumHashID::to_hex)
+            .collect::<Vec<String>>()
+            .join(sep)
+    }
+
+    /// Parse a batch of `WasmiumHashID`s written by `join_hex`, an empty string is an empty batch
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let ids = [WasmiumHashID::new(blake3::hash(b"foo")), WasmiumHashID::new(blake3::hash(b"bar"))];
+    /// let joined = WasmiumHashID::join_hex(&ids, " | ");
+    ///
+    /// assert_eq!(WasmiumHashID::parse_joined_hex(&joined, " | "), Ok(ids.to_vec()));
+    /// assert_eq!(WasmiumHashID::parse_joined_hex("", ","), Ok(Vec::new()));
+    /// assert_eq!(
+    ///     WasmiumHashID::parse_joined_hex(&joined, ","),
+    ///     Err(Error::InvalidLength(179))
+    /// );
+    /// ```
+    pub fn parse_joined_hex(s: &str, sep: &str) -> Result<Vec<WasmiumHashID>, Error> {
+        if s.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        s.split(sep).map(WasmiumHashID::parse_hex_into).collect()
+    }
+}
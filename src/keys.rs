@@ -0,0 +1,429 @@
+use crate::{Error, WasmiumHashID, HASH_LEN, ID_LEN, TIMESTAMP_LEN};
+
+/// The Blake3 key derivation context for `WasmiumHashID::nonce12`
+const NONCE_CONTEXT: &str = "wasmium-hash-id nonce12 v1";
+
+impl WasmiumHashID {
+    /// Read 8 bytes of the Blake3 hash starting at `offset` as a big endian `u64`
+    pub(crate) fn digest_u64(&self, offset: usize) -> u64 {
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&self.blake3hash[offset..offset + 8]);
+
+        u64::from_be_bytes(word)
+    }
+
+    /// Copy the first 16 bytes of the Blake3 hash
+    fn digest_prefix16(&self) -> [u8; 16] {
+        let mut prefix = [0u8; 16];
+        prefix.copy_from_slice(&self.blake3hash[..16]);
+
+        prefix
+    }
+
+    /// Derive `k` hash values for a bloom filter from the Blake3 hash using double hashing,
+    /// `g1 + i * g2` where `g1` and `g2` are the first two big endian `u64`s of the hash.
+    /// `g2` is forced to be odd so that the `k` values are always distinct
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let hashes = hash_id.bloom_hashes(7);
+    ///
+    /// assert_eq!(hashes.len(), 7);
+    /// assert_eq!(hashes, hash_id.bloom_hashes(7));
+    ///
+    /// let mut unique = hashes.clone();
+    /// unique.sort_unstable();
+    /// unique.dedup();
+    /// assert_eq!(unique.len(), 7);
+    /// ```
+    pub fn bloom_hashes(&self, k: usize) -> Vec<u64> {
+        let g1 = self.digest_u64(0);
+        let g2 = self.digest_u64(8) | 1;
+
+        (0..k as u64)
+            .map(|index| g1.wrapping_add(index.wrapping_mul(g2)))
+            .collect()
+    }
+
+    /// Get a key for hash tables and byte prefix based indexes where the Blake3 hash leads
+    /// and the TAI64N timestamp trails, so recently created IDs do not cluster on shared prefixes.
+    /// This is a transform for keying only, it is not a storage format and
+    /// is not accepted by any of the decoding methods
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let key = hash_id.mixed_key();
+    ///
+    /// assert_eq!(key, hash_id.mixed_key());
+    /// assert_ne!(key, hash_id.build());
+    /// assert_eq!(&key[..32], blake3::hash(b"foo").as_bytes());
+    /// ```
+    pub fn mixed_key(&self) -> [u8; ID_LEN] {
+        let mut key = self.build();
+        key.rotate_left(TIMESTAMP_LEN);
+
+        key
+    }
+
+    /// Get a stable 64 bit fingerprint of the `WasmiumHashID` for sampling and consistent hashing,
+    /// the first 8 bytes of the Blake3 hash read as a big endian `u64`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let blake3_hash = blake3::hash(b"foo");
+    /// let hash_id = WasmiumHashID::new(blake3_hash);
+    ///
+    /// let mut first_bytes = [0u8; 8];
+    /// first_bytes.copy_from_slice(&blake3_hash.as_bytes()[..8]);
+    ///
+    /// assert_eq!(hash_id.fingerprint64(), u64::from_be_bytes(first_bytes));
+    /// assert_eq!(hash_id.fingerprint64(), WasmiumHashID::new(blake3_hash).fingerprint64());
+    /// ```
+    pub fn fingerprint64(&self) -> u64 {
+        self.digest_u64(0)
+    }
+
+    /// Fold the Blake3 hash into 128 bits by XORing its two 16 byte halves,
+    /// keeping entropy from the whole hash unlike a plain truncation
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let mut first = [1u8; 32];
+    /// let mut second = first;
+    /// second[31] = 2;
+    ///
+    /// let first = WasmiumHashID::new(blake3::Hash::from(first));
+    /// let second = WasmiumHashID::new(blake3::Hash::from(second));
+    ///
+    /// assert_eq!(first.fold128(), first.fold128());
+    /// assert_ne!(first.fold128(), second.fold128());
+    /// assert_eq!(first.fold128(), 0);
+    /// ```
+    pub fn fold128(&self) -> u128 {
+        let mut folded = [0u8; 16];
+        folded
+            .iter_mut()
+            .zip(self.blake3hash[..16].iter().zip(&self.blake3hash[16..]))
+            .for_each(|(byte, (high, low))| *byte = high ^ low);
+
+        u128::from_be_bytes(folded)
+    }
+
+    /// Get the seed for rendering a visual identifier such as an identicon,
+    /// the first 4 bytes of the Blake3 hash used as RGB plus an index
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let blake3_hash = blake3::hash(b"foo");
+    /// let seed = WasmiumHashID::new(blake3_hash).visual_seed();
+    ///
+    /// assert_eq!(seed, WasmiumHashID::new(blake3_hash).visual_seed());
+    /// assert_eq!(seed, blake3_hash.as_bytes()[..4]);
+    /// ```
+    pub fn visual_seed(&self) -> [u8; 4] {
+        let mut seed = [0u8; 4];
+        seed.copy_from_slice(&self.blake3hash[..4]);
+
+        seed
+    }
+
+    /// Read the first 16 bytes of the Blake3 hash as a little endian `u128`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let mut digest = [0u8; 32];
+    /// digest.iter_mut().enumerate().for_each(|(index, byte)| *byte = index as u8);
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::Hash::from(digest));
+    /// assert_eq!(hash_id.digest_u128_le(), 0x0f0e0d0c_0b0a0908_07060504_03020100);
+    /// ```
+    pub fn digest_u128_le(&self) -> u128 {
+        u128::from_le_bytes(self.digest_prefix16())
+    }
+
+    /// Read the first 16 bytes of the Blake3 hash as a big endian `u128`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let mut digest = [0u8; 32];
+    /// digest.iter_mut().enumerate().for_each(|(index, byte)| *byte = index as u8);
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::Hash::from(digest));
+    /// assert_eq!(hash_id.digest_u128_be(), 0x00010203_04050607_08090a0b_0c0d0e0f);
+    /// ```
+    pub fn digest_u128_be(&self) -> u128 {
+        u128::from_be_bytes(self.digest_prefix16())
+    }
+
+    /// Get the position of the `WasmiumHashID` on a consistent hashing ring of `ring_size` slots,
+    /// `fingerprint64() % ring_size`. Errors with `Error::InvalidShardCount` if `ring_size` is zero
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// for index in 0u32..100 {
+    ///     let hash_id = WasmiumHashID::new(blake3::hash(&index.to_le_bytes()));
+    ///     let position = hash_id.ring_position(7).unwrap();
+    ///
+    ///     assert!(position < 7);
+    ///     assert_eq!(Ok(position), hash_id.ring_position(7));
+    /// }
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// assert_eq!(hash_id.ring_position(0), Err(Error::InvalidShardCount(0)));
+    /// ```
+    pub fn ring_position(&self, ring_size: u64) -> Result<u64, Error> {
+        self.fingerprint64()
+            .checked_rem(ring_size)
+            .ok_or(Error::InvalidShardCount(ring_size))
+    }
+
+    /// Get a stable, uniformly distributed weight in `[0, 1)` for threshold sampling.
+    /// The top 53 bits of `fingerprint64()` are divided by 2^53, which unlike dividing by
+    /// `u64::MAX` can never round up to 1.0
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// for index in 0u32..100 {
+    ///     let hash_id = WasmiumHashID::new(blake3::hash(&index.to_le_bytes()));
+    ///     let weight = hash_id.sample_weight();
+    ///
+    ///     assert!((0.0..1.0).contains(&weight));
+    ///     assert_eq!(weight, hash_id.sample_weight());
+    /// }
+    /// ```
+    pub fn sample_weight(&self) -> f64 {
+        (self.fingerprint64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Count the set bits of the Blake3 hash, which over many random IDs should hover
+    /// around 128. This is a weak statistical smoke test, not a guarantee of randomness
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// assert_eq!(WasmiumHashID::empty().popcount_digest(), 128);
+    /// assert!(WasmiumHashID::new(blake3::hash(b"foo")).popcount_digest() <= 256);
+    /// assert_eq!(WasmiumHashID::new([0xffu8; 32].into()).popcount_digest(), 256);
+    /// ```
+    pub fn popcount_digest(&self) -> u32 {
+        self.blake3hash.iter().map(|byte| byte.count_ones()).sum()
+    }
+
+    /// Split the Blake3 hash into overlapping `k` byte windows and hash each window to a `u64`,
+    /// the first 8 bytes of its Blake3 hash read big-endian, for MinHash style features.
+    /// Returns `HASH_LEN - k + 1` values, or none when `k` is zero or larger than `HASH_LEN`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    ///
+    /// for k in [1, 4, 8, 32] {
+    ///     assert_eq!(hash_id.digest_kmers(k).len(), 32 - k + 1);
+    ///     assert_eq!(hash_id.digest_kmers(k), hash_id.digest_kmers(k));
+    /// }
+    /// assert!(hash_id.digest_kmers(0).is_empty());
+    /// assert!(hash_id.digest_kmers(33).is_empty());
+    /// ```
+    pub fn digest_kmers(&self, k: usize) -> Vec<u64> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        self.blake3hash
+            .windows(k)
+            .map(|window| {
+                let mut bytes = [0u8; 8];
+                bytes.copy_from_slice(&blake3::hash(window).as_bytes()[..8]);
+                u64::from_be_bytes(bytes)
+            })
+            .collect()
+    }
+
+    /// Get the rendezvous (highest random weight) score of this ID for a node, the Blake3 hash
+    /// of `digest || node_id` folded to 64 bits by XORing its four big endian `u64` words.
+    /// Placing each ID on the node with the highest score moves few IDs when nodes change
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let nodes: [&[u8]; 3] = [b"node-a", b"node-b", b"node-c"];
+    ///
+    /// let scores = nodes.map(|node| hash_id.rendezvous_score(node));
+    /// assert_eq!(scores, nodes.map(|node| hash_id.rendezvous_score(node)));
+    /// assert_ne!(scores[0], scores[1]);
+    /// assert_ne!(scores[1], scores[2]);
+    /// ```
+    pub fn rendezvous_score(&self, node_id: &[u8]) -> u64 {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&self.blake3hash);
+        hasher.update(node_id);
+
+        hasher
+            .finalize()
+            .as_bytes()
+            .chunks_exact(8)
+            .map(|chunk| {
+                let mut word = [0u8; 8];
+                word.copy_from_slice(chunk);
+                u64::from_be_bytes(word)
+            })
+            .fold(0, |folded, word| folded ^ word)
+    }
+
+    /// Pack the `WasmiumHashID` into a 16 byte time ordered key: the low 6 bytes of the TAI64
+    /// seconds label followed by the first 10 bytes of the Blake3 hash. The nanosecond subfield
+    /// and the top 2 bytes of the label are dropped, so keys only sort by time at one second
+    /// resolution, IDs within the same second sort by hash, and 48 bits of seconds wrap after
+    /// about 8.9 million years. 80 bits of hash are far more collision prone than the full 256
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let start = Tai64N::now();
+    /// let ids = (0u64..5)
+    ///     .map(|seconds| {
+    ///         WasmiumHashID::new_with_timestamp(
+    ///             blake3::hash(&seconds.to_le_bytes()),
+    ///             start + Duration::from_secs(seconds * 3),
+    ///         )
+    ///     })
+    ///     .collect::<Vec<WasmiumHashID>>();
+    ///
+    /// let keys = ids.iter().map(WasmiumHashID::compact16).collect::<Vec<[u8; 16]>>();
+    /// let mut sorted = keys.clone();
+    /// sorted.sort();
+    ///
+    /// assert_eq!(keys, sorted);
+    /// assert_eq!(&keys[0][6..], &ids[0].digest_bytes()[..10]);
+    /// ```
+    pub fn compact16(&self) -> [u8; 16] {
+        let mut compact = [0u8; 16];
+        compact[..6].copy_from_slice(&self.tai_timestamp[2..8]);
+        compact[6..].copy_from_slice(&self.blake3hash[..10]);
+
+        compact
+    }
+
+    /// Suggest how many bytes of the Blake3 hash to keep when truncating so that `n_items`
+    /// truncated hashes collide with probability at most about `target_p`, using the birthday
+    /// bound `p ≈ n² / 2^(bits + 1)`. The result is clamped to `1..=HASH_LEN`, a `target_p`
+    /// that is not positive or is `NaN` asks for the full hash
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// // 2^32 items have even odds of colliding in 64 bits
+    /// assert_eq!(WasmiumHashID::bytes_for_collision_probability(1 << 32, 0.5), 8);
+    /// assert_eq!(WasmiumHashID::bytes_for_collision_probability(1_000_000, 1e-9), 9);
+    /// assert_eq!(WasmiumHashID::bytes_for_collision_probability(1_000_000_000, 1e-12), 13);
+    /// assert_eq!(WasmiumHashID::bytes_for_collision_probability(2, 0.5), 1);
+    /// assert_eq!(WasmiumHashID::bytes_for_collision_probability(u64::MAX, 1e-30), 29);
+    /// assert_eq!(WasmiumHashID::bytes_for_collision_probability(u64::MAX, 1e-100), 32);
+    /// assert_eq!(WasmiumHashID::bytes_for_collision_probability(1000, 0.0), 32);
+    /// ```
+    pub fn bytes_for_collision_probability(n_items: u64, target_p: f64) -> usize {
+        if target_p.is_nan() || target_p <= 0.0 {
+            return HASH_LEN;
+        }
+        if n_items < 2 || target_p >= 1.0 {
+            return 1;
+        }
+
+        let bits = 2.0 * (n_items as f64).log2() - 1.0 - target_p.log2();
+
+        ((bits / 8.0).ceil() as usize).clamp(1, HASH_LEN)
+    }
+
+    /// Derive a 12 byte AEAD nonce from the built `WasmiumHashID`, the first 12 bytes of its
+    /// Blake3 key derivation under a fixed context. Nonces are only unique as long as the IDs
+    /// are, encrypting twice under one key with the same ID reuses the nonce
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let first = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let second = WasmiumHashID::new(blake3::hash(b"bar"));
+    ///
+    /// assert_eq!(first.nonce12(), first.nonce12());
+    /// assert_ne!(first.nonce12(), second.nonce12());
+    /// ```
+    pub fn nonce12(&self) -> [u8; 12] {
+        let mut nonce = [0u8; 12];
+        nonce.copy_from_slice(&blake3::derive_key(NONCE_CONTEXT, &self.build())[..12]);
+
+        nonce
+    }
+
+    /// Get the recommended key for storing this ID in a radix trie, the borrowed Blake3 hash.
+    /// Hashes are uniformly distributed and keep the trie balanced, while full IDs share
+    /// long timestamp prefixes that cluster by time and deepen the trie
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    ///
+    /// assert!(std::ptr::eq(hash_id.trie_key(), hash_id.digest_bytes()));
+    /// assert_eq!(hash_id.trie_key(), blake3::hash(b"foo").as_bytes());
+    /// ```
+    pub fn trie_key(&self) -> &[u8; HASH_LEN] {
+        &self.blake3hash
+    }
+
+    /// Get the Blake3 hash as a 256 bit big-endian integer in four `u64` limbs,
+    /// most significant limb first, for big integer libraries and EVM style contexts
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let mut digest = [0u8; 32];
+    /// digest[0] = 0x01;
+    /// digest[31] = 0xff;
+    /// let limbs = WasmiumHashID::new(digest.into()).digest_as_u256_be();
+    ///
+    /// assert_eq!(limbs, [0x0100_0000_0000_0000, 0, 0, 0xff]);
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let bytes = hash_id
+    ///     .digest_as_u256_be()
+    ///     .iter()
+    ///     .flat_map(|limb| limb.to_be_bytes())
+    ///     .collect::<Vec<u8>>();
+    /// assert_eq!(&bytes[..], hash_id.digest_bytes());
+    /// ```
+    pub fn digest_as_u256_be(&self) -> [u64; 4] {
+        [0, 8, 16, 24].map(|offset| self.digest_u64(offset))
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{Error, WasmiumHashID, HASH_LEN, ID_LEN, TIMESTAMP_LEN};
+
+/// The length of a `WasmiumHashID` padded with zero bytes to a multiple of 16 bytes
+pub const PADDED_ID_LEN: usize = 48;
+
+/// The type tag prefixed to a `WasmiumHashID` by `to_tagged`, ASCII `w`
+pub const ID_TAG: u8 = 0x77;
+
+/// The length of a `WasmiumHashID` prefixed with `ID_TAG`
+pub const TAGGED_ID_LEN: usize = ID_LEN + 1;
+
+impl WasmiumHashID {
+    /// Build the `WasmiumHashID` in a byte array padded with 4 trailing zero bytes to 48 bytes,
+    /// a multiple of 16 bytes. Only the length is padded, the address of the array is not aligned
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let padded = hash_id.build_padded();
+    ///
+    /// assert_eq!(&padded[..44], &hash_id.build());
+    /// assert_eq!(&padded[44..], &[0u8; 4]);
+    /// ```
+    pub fn build_padded(&self) -> [u8; PADDED_ID_LEN] {
+        let mut padded = [0u8; PADDED_ID_LEN];
+        padded[..ID_LEN].copy_from_slice(&self.build());
+
+        padded
+    }
+
+    /// Restore a `WasmiumHashID` from a byte array built by `build_padded`,
+    /// validating that the padding bytes are zero and that the TAI64N timestamp is valid
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let mut padded = hash_id.build_padded();
+    /// assert_eq!(WasmiumHashID::from_padded(padded), Ok(hash_id));
+    ///
+    /// padded[47] = 1;
+    /// assert_eq!(WasmiumHashID::from_padded(padded), Err(Error::InvalidPadding));
+    /// ```
+    pub fn from_padded(padded: [u8; PADDED_ID_LEN]) -> Result<WasmiumHashID, Error> {
+        if padded[ID_LEN..].iter().any(|byte| *byte != 0) {
+            return Err(Error::InvalidPadding);
+        }
+
+        WasmiumHashID::from_slice(&padded[..ID_LEN])
+    }
+
+    /// Read the padded form from `build_padded` as six big-endian `u64` words.
+    /// The last word holds the final 4 bytes of the Blake3 hash in its high half
+    /// and the 4 zero padding bytes in its low half
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let words = hash_id.as_u64_words();
+    ///
+    /// let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_be_bytes()).collect();
+    /// assert_eq!(&bytes[..], &hash_id.build_padded()[..]);
+    /// assert_eq!(words[5] & 0xffff_ffff, 0);
+    /// ```
+    pub fn as_u64_words(&self) -> [u64; PADDED_ID_LEN / 8] {
+        let padded = self.build_padded();
+        let mut words = [0u64; PADDED_ID_LEN / 8];
+
+        for (word, chunk) in words.iter_mut().zip(padded.chunks_exact(8)) {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            *word = u64::from_be_bytes(bytes);
+        }
+
+        words
+    }
+
+    /// Build the `WasmiumHashID` prefixed with the 1 byte type tag `ID_TAG`,
+    /// so a generic decoder can recognize it in a stream of mixed tagged records
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{WasmiumHashID, ID_TAG};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let tagged = hash_id.to_tagged();
+    ///
+    /// assert_eq!(tagged[0], ID_TAG);
+    /// assert_eq!(&tagged[1..], &hash_id.build());
+    /// ```
+    pub fn to_tagged(&self) -> [u8; TAGGED_ID_LEN] {
+        let mut tagged = [0u8; TAGGED_ID_LEN];
+        tagged[0] = ID_TAG;
+        tagged[1..].copy_from_slice(&self.build());
+
+        tagged
+    }
+
+    /// Restore a `WasmiumHashID` from a byte array built by `to_tagged`,
+    /// validating the type tag and the TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let mut tagged = hash_id.to_tagged();
+    /// assert_eq!(WasmiumHashID::from_tagged(tagged), Ok(hash_id));
+    ///
+    /// tagged[0] = b'x';
+    /// assert_eq!(WasmiumHashID::from_tagged(tagged), Err(Error::InvalidEncoding));
+    /// ```
+    pub fn from_tagged(tagged: [u8; TAGGED_ID_LEN]) -> Result<WasmiumHashID, Error> {
+        if tagged[0] != ID_TAG {
+            return Err(Error::InvalidEncoding);
+        }
+
+        WasmiumHashID::from_slice(&tagged[1..])
+    }
+
+    /// Get the size of a `WasmiumHashID` relative to the raw Blake3 hash it carries,
+    /// `ID_LEN / HASH_LEN` which is 1.375, for capacity planning
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// assert_eq!(WasmiumHashID::overhead_ratio(), 1.375);
+    /// ```
+    pub fn overhead_ratio() -> f64 {
+        ID_LEN as f64 / HASH_LEN as f64
+    }
+
+    /// Get the number of bytes the embedded TAI64N timestamp adds to every `WasmiumHashID`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{WasmiumHashID, HASH_LEN, ID_LEN};
+    ///
+    /// assert_eq!(WasmiumHashID::timestamp_overhead_bytes(), 12);
+    /// assert_eq!(WasmiumHashID::timestamp_overhead_bytes(), ID_LEN - HASH_LEN);
+    /// ```
+    pub fn timestamp_overhead_bytes() -> usize {
+        TIMESTAMP_LEN
+    }
+}
@@ -33,11 +33,90 @@
 //!
 //!
 
+#[cfg(feature = "tokio")]
+mod async_io;
+mod base32;
+mod base45;
+#[cfg(feature = "base64")]
+mod base64;
+mod batch;
+#[cfg(feature = "serde")]
+mod canonical;
+mod chain;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "cid")]
+mod cid;
+#[cfg(feature = "constant_time")]
+mod constant_time;
+mod counter;
+#[cfg(feature = "crc32fast")]
+mod crc;
+mod delta;
+mod derive;
+mod diff;
+mod digest;
+mod error;
+#[cfg(feature = "generic-array")]
+mod generic_array;
+mod hasher;
+mod hex;
+mod keys;
+mod layout;
+mod mac;
+#[cfg(feature = "mnemonic")]
+mod mnemonic;
+mod ordering;
+mod seeded;
+#[cfg(feature = "siphash")]
+mod siphash;
+#[cfg(feature = "test-util")]
+mod test_util;
+mod time;
+mod timestamp;
+#[cfg(feature = "ulid")]
+mod ulid;
+#[cfg(feature = "uuid")]
+mod uuid;
+mod work;
+
+pub use counter::WasmiumHashIDCounter;
+pub use diff::IdDiff;
+pub use digest::{Blake3, Blake3DeriveKey, Blake3Keyed, DigestFn};
+pub use error::Error;
+pub use hasher::{TeeHasher, WasmiumHashIDKeyedHasher};
+pub use layout::{ID_TAG, PADDED_ID_LEN, TAGGED_ID_LEN};
+pub use seeded::WasmiumHashIDSeeded;
+pub use timestamp::HashIDTimestamp;
+
+use std::collections::HashSet;
+
 use borsh::{BorshDeserialize, BorshSerialize};
 use tai64::Tai64N;
 
 #[cfg(feature = "random_id")]
 use nanorand::{BufferedRng, ChaCha8, Rng};
+#[cfg(feature = "random_id")]
+use std::time::Duration;
+
+/// The length of the TAI64N timestamp in bytes
+pub const TIMESTAMP_LEN: usize = 12;
+
+/// The length of the Blake3 hash in bytes
+pub const HASH_LEN: usize = 32;
+
+/// The length of a `WasmiumHashID` built into a byte array
+pub const ID_LEN: usize = TIMESTAMP_LEN + HASH_LEN;
+
+/// The version of the current `WasmiumHashID` byte layout,
+/// a 12 byte TAI64N timestamp followed by a 32 byte Blake3 hash
+pub const VERSION: u8 = 1;
+
+/// The Blake3 hash of the empty input
+pub const EMPTY_DIGEST: [u8; HASH_LEN] = [
+    0xaf, 0x13, 0x49, 0xb9, 0xf5, 0xf9, 0xa1, 0xa6, 0xa0, 0x40, 0x4d, 0xea, 0x36, 0xdc, 0xc9, 0x49,
+    0x9b, 0xcb, 0x25, 0xc9, 0xad, 0xc1, 0x12, 0xb7, 0xcc, 0x9a, 0x93, 0xca, 0xe4, 0x1f, 0x32, 0x62,
+];
 
 /// ### Structure
 /// ```rust
@@ -68,12 +147,214 @@ impl WasmiumHashID {
     /// // Generate the hash
     /// let hash_id = WasmiumHashID::new(blake3_hash);
     /// ```
+    ///
+    /// #### Tracing
+    /// With the `tracing` feature enabled, creating a `WasmiumHashID` with a constructor such as
+    /// `new*`, `rand*`, `rekey` or `truncated_time` emits a `TRACE` event carrying the first 8 hex
+    /// characters of the Blake3 hash and the TAI64N seconds and nanoseconds as structured fields.
+    /// Calling `build` is silent
+    /// ```rust
+    /// # #[cfg(feature = "tracing")]
+    /// # {
+    /// use std::sync::{Arc, Mutex};
+    /// use tracing::{field::{Field, Visit}, span, Event, Metadata, Subscriber};
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// #[derive(Default)]
+    /// struct Fields(Vec<(String, String)>);
+    ///
+    /// impl Visit for Fields {
+    ///     fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+    ///         self.0.push((field.name().to_owned(), format!("{:?}", value)));
+    ///     }
+    /// }
+    ///
+    /// #[derive(Clone, Default)]
+    /// struct Capture(Arc<Mutex<Vec<Fields>>>);
+    ///
+    /// impl Subscriber for Capture {
+    ///     fn enabled(&self, _: &Metadata<'_>) -> bool { true }
+    ///     fn new_span(&self, _: &span::Attributes<'_>) -> span::Id { span::Id::from_u64(1) }
+    ///     fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+    ///     fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+    ///     fn event(&self, event: &Event<'_>) {
+    ///         let mut fields = Fields::default();
+    ///         event.record(&mut fields);
+    ///         self.0.lock().unwrap().push(fields);
+    ///     }
+    ///     fn enter(&self, _: &span::Id) {}
+    ///     fn exit(&self, _: &span::Id) {}
+    /// }
+    ///
+    /// let capture = Capture::default();
+    /// let blake3_hash = blake3::hash(b"foo");
+    /// tracing::subscriber::with_default(capture.clone(), || WasmiumHashID::new(blake3_hash).build());
+    ///
+    /// let events = capture.0.lock().unwrap();
+    /// assert_eq!(events.len(), 1);
+    /// let fields = &events[0].0;
+    /// assert!(fields.contains(&("operation".to_owned(), "\"new\"".to_owned())));
+    /// assert!(fields.contains(&(
+    ///     "digest".to_owned(),
+    ///     format!("{:?}", &blake3_hash.to_hex()[..8])
+    /// )));
+    /// assert!(fields.iter().any(|(name, _)| name == "tai64_seconds"));
+    /// assert!(fields.iter().any(|(name, _)| name == "tai64_nanos"));
+    /// # }
+    /// ```
     pub fn new(blake3hash: blake3::Hash) -> WasmiumHashID {
-        WasmiumHashID {
+        let hash_id = WasmiumHashID {
             tai_timestamp: Tai64N::now().to_bytes(),
             blake3hash: *blake3hash.as_bytes(),
-        }
+        };
+        #[cfg(feature = "tracing")]
+        hash_id.trace("new");
+
+        hash_id
+    }
+
+    /// Generate a new `WasmiumHashID` with the given TAI64N timestamp instead of the current time
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let timestamp = Tai64N::now();
+    /// let hash_id = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), timestamp).build();
+    ///
+    /// assert_eq!(WasmiumHashID::get_timestamp(hash_id), Ok(timestamp));
+    /// ```
+    pub fn new_with_timestamp(blake3hash: blake3::Hash, timestamp: Tai64N) -> WasmiumHashID {
+        let hash_id = WasmiumHashID {
+            tai_timestamp: timestamp.to_bytes(),
+            blake3hash: *blake3hash.as_bytes(),
+        };
+        #[cfg(feature = "tracing")]
+        hash_id.trace("new_with_timestamp");
+
+        hash_id
+    }
+
+    /// Reproduce a `WasmiumHashID` from the preimage that was hashed and the TAI64N timestamp
+    /// it was recorded with, for example the preimage returned by `rand32_with_preimage`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let preimage = [3u8; 32];
+    /// let hash_id = WasmiumHashID::new(blake3::hash(&preimage));
+    /// let timestamp = WasmiumHashID::get_timestamp(hash_id.build()).unwrap();
+    ///
+    /// let rebuilt = WasmiumHashID::rebuild_from_preimage(&preimage, timestamp);
+    /// assert_eq!(rebuilt.build(), hash_id.build());
+    /// ```
+    pub fn rebuild_from_preimage(preimage: &[u8], timestamp: Tai64N) -> WasmiumHashID {
+        WasmiumHashID::new_with_timestamp(blake3::hash(preimage), timestamp)
+    }
+
+    /// Generate a new `WasmiumHashID` by finalizing an incrementally updated `blake3::Hasher`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let mut hasher = blake3::Hasher::new();
+    /// hasher.update(b"foo");
+    /// hasher.update(b"bar");
+    ///
+    /// let hash_id = WasmiumHashID::from_hasher(hasher.clone()).build();
+    /// let expected = WasmiumHashID::new(hasher.finalize()).build();
+    ///
+    /// assert_eq!(hash_id[12..], expected[12..]);
+    /// ```
+    pub fn from_hasher(hasher: blake3::Hasher) -> WasmiumHashID {
+        WasmiumHashID::new(hasher.finalize())
+    }
+
+    /// Generate a new `WasmiumHashID` from a raw 32 byte digest, stamped with the current time
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let digest = *blake3::hash(b"foo").as_bytes();
+    /// let hash_id = WasmiumHashID::from_digest_bytes(digest);
+    /// let expected = WasmiumHashID::new(digest.into());
+    ///
+    /// assert_eq!(hash_id.digest_bytes(), expected.digest_bytes());
+    /// assert!(hash_id.timestamp_bytes() <= expected.timestamp_bytes());
+    /// ```
+    pub fn from_digest_bytes(digest: [u8; HASH_LEN]) -> WasmiumHashID {
+        WasmiumHashID::new(digest.into())
+    }
+
+    /// Generate a new `WasmiumHashID` and build it in one call, the same as `new(hash).build()`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let before = WasmiumHashID::new(blake3::hash(b"foo")).build();
+    /// let hash_id = WasmiumHashID::bytes_from_hash(blake3::hash(b"foo"));
+    /// let after = WasmiumHashID::new(blake3::hash(b"foo")).build();
+    ///
+    /// assert_eq!(hash_id[12..], before[12..]);
+    /// assert!(before[..12] <= hash_id[..12] && hash_id[..12] <= after[..12]);
+    /// ```
+    pub fn bytes_from_hash(hash: blake3::Hash) -> [u8; ID_LEN] {
+        WasmiumHashID::new(hash).build()
+    }
+
+    /// Detect the layout version of a `WasmiumHashID` byte array.
+    /// The current layout has no room for a version byte, so this returns `VERSION`
+    /// when the bytes decode as the current layout and `None` when the timestamp is invalid.
+    /// A future layout carrying an explicit version byte would need a new, larger type
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{WasmiumHashID, VERSION};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo")).build();
+    /// assert_eq!(WasmiumHashID::version(&hash_id), Some(VERSION));
+    ///
+    /// assert_eq!(WasmiumHashID::version(&[0xff; 44]), None);
+    /// ```
+    pub fn version(hash_id: &[u8; ID_LEN]) -> Option<u8> {
+        Tai64N::from_slice(&hash_id[..TIMESTAMP_LEN])
+            .ok()
+            .map(|_| VERSION)
+    }
+
+    /// Generate a new `WasmiumHashID` representing no content, the Blake3 hash of the empty input
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{WasmiumHashID, EMPTY_DIGEST};
+    ///
+    /// let hash_id = WasmiumHashID::empty();
+    ///
+    /// assert_eq!(blake3::hash(b"").as_bytes(), &EMPTY_DIGEST);
+    /// assert_eq!(hash_id.build()[12..], EMPTY_DIGEST);
+    /// ```
+    pub fn empty() -> WasmiumHashID {
+        WasmiumHashID::new(blake3::hash(b""))
+    }
+
+    /// Check whether the Blake3 hash is the hash of the empty input
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// assert!(WasmiumHashID::empty().is_empty_digest());
+    /// assert!(!WasmiumHashID::new(blake3::hash(b"foo")).is_empty_digest());
+    /// ```
+    pub fn is_empty_digest(&self) -> bool {
+        self.blake3hash == EMPTY_DIGEST
     }
+
     /// Build the `WasmiumHashID` in a byte array
     ///
     ///
@@ -98,6 +379,95 @@ impl WasmiumHashID {
         hash_id
     }
 
+    /// Borrow the TAI64N timestamp bytes
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    ///
+    /// assert_eq!(hash_id.timestamp_bytes(), &hash_id.build()[..12]);
+    /// ```
+    pub fn timestamp_bytes(&self) -> &[u8; TIMESTAMP_LEN] {
+        &self.tai_timestamp
+    }
+
+    /// Borrow the Blake3 hash bytes
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let blake3_hash = blake3::hash(b"foo");
+    /// let hash_id = WasmiumHashID::new(blake3_hash);
+    ///
+    /// assert_eq!(hash_id.digest_bytes(), blake3_hash.as_bytes());
+    /// ```
+    pub fn digest_bytes(&self) -> &[u8; HASH_LEN] {
+        &self.blake3hash
+    }
+
+    /// Get the Blake3 hash as an owned `blake3::Hash` for further hashing, infallible
+    /// unlike `get_blake3_hash` since a `WasmiumHashID` always holds a full hash
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let blake3_hash = blake3::hash(b"foo");
+    ///
+    /// assert_eq!(WasmiumHashID::new(blake3_hash).to_blake3(), blake3_hash);
+    /// ```
+    pub fn to_blake3(&self) -> blake3::Hash {
+        blake3::Hash::from(self.blake3hash)
+    }
+
+    /// Check whether `bytes` is this `WasmiumHashID` as built by `build`,
+    /// comparing the fields in place without building or parsing
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let mut bytes = hash_id.build();
+    /// assert!(hash_id.eq_bytes(&bytes));
+    ///
+    /// bytes[43] ^= 1;
+    /// assert!(!hash_id.eq_bytes(&bytes));
+    /// ```
+    pub fn eq_bytes(&self, bytes: &[u8; ID_LEN]) -> bool {
+        bytes[..TIMESTAMP_LEN] == self.tai_timestamp && bytes[TIMESTAMP_LEN..] == self.blake3hash
+    }
+
+    /// Deserialize a `WasmiumHashID` with borsh and validate its TAI64N timestamp.
+    /// The derived `BorshDeserialize` accepts any 44 bytes and remains available for trusted input
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use borsh::{BorshDeserialize, BorshSerialize};
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let mut bytes = hash_id.try_to_vec().unwrap();
+    /// assert_eq!(WasmiumHashID::try_from_borsh(&bytes), Ok(hash_id));
+    ///
+    /// bytes[8..12].copy_from_slice(&u32::MAX.to_be_bytes());
+    /// assert_eq!(
+    ///     WasmiumHashID::try_from_borsh(&bytes),
+    ///     Err(Error::Timestamp(tai64::Error::NanosInvalid))
+    /// );
+    /// assert!(WasmiumHashID::try_from_slice(&bytes).is_ok());
+    /// ```
+    pub fn try_from_borsh(bytes: &[u8]) -> Result<WasmiumHashID, Error> {
+        let hash_id = <WasmiumHashID as BorshDeserialize>::try_from_slice(bytes)
+            .map_err(|error| Error::Serialization(error.to_string()))?;
+        hash_id.tai64n()?;
+
+        Ok(hash_id)
+    }
+
     /// Restore the timestamp from a byte array hash
     ///
     /// //! #### Usage
@@ -116,7 +486,7 @@ impl WasmiumHashID {
     /// let timestamp = WasmiumHashID::get_timestamp(hash_id);
     /// ```
     pub fn get_timestamp(hash_id: [u8; 44]) -> Result<Tai64N, tai64::Error> {
-        Ok(Tai64N::from_slice(&hash_id[0..=11])?)
+        Tai64N::from_slice(&hash_id[0..=11])
     }
 
     /// Restore the original Blake3 hash
@@ -139,14 +509,119 @@ impl WasmiumHashID {
     pub fn get_blake3_hash(
         hash_id: [u8; 44],
     ) -> Result<blake3::Hash, std::array::TryFromSliceError> {
-        let hash_array: [u8; 32] = match hash_id[12..].try_into() {
-            Ok(hash_array) => hash_array,
-            Err(error) => return Err(error),
-        };
+        let hash_array: [u8; 32] = hash_id[12..].try_into()?;
 
         Ok(hash_array.into())
     }
 
+    /// Check that the Blake3 hash of a `WasmiumHashID` byte array equals `expected`,
+    /// the error holds both hashes
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let blake3_hash = blake3::hash(b"foo");
+    /// let hash_id = WasmiumHashID::new(blake3_hash).build();
+    /// assert_eq!(WasmiumHashID::assert_digest(hash_id, blake3_hash), Ok(()));
+    ///
+    /// let error = WasmiumHashID::assert_digest(hash_id, blake3::hash(b"bar")).unwrap_err();
+    /// assert_eq!(
+    ///     error,
+    ///     Error::DigestMismatch {
+    ///         expected: *blake3::hash(b"bar").as_bytes(),
+    ///         found: *blake3_hash.as_bytes(),
+    ///     }
+    /// );
+    /// assert!(error.to_string().contains(blake3_hash.to_hex().as_str()));
+    /// assert!(error.to_string().contains(blake3::hash(b"bar").to_hex().as_str()));
+    /// ```
+    pub fn assert_digest(hash_id: [u8; ID_LEN], expected: blake3::Hash) -> Result<(), Error> {
+        let mut found = [0u8; HASH_LEN];
+        found.copy_from_slice(&hash_id[TIMESTAMP_LEN..]);
+
+        if blake3::Hash::from(found) == expected {
+            Ok(())
+        } else {
+            Err(Error::DigestMismatch {
+                expected: *expected.as_bytes(),
+                found,
+            })
+        }
+    }
+
+    /// Check that the TAI64N timestamp of a `WasmiumHashID` byte array is within
+    /// `min_time..=max_time` and that its Blake3 hash is in `allowed_digests`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::{collections::HashSet, time::Duration};
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let timestamp = Tai64N::now();
+    /// let min_time = timestamp - Duration::from_secs(60);
+    /// let max_time = timestamp + Duration::from_secs(60);
+    /// let allowed = HashSet::from([*blake3::hash(b"foo").as_bytes()]);
+    ///
+    /// let hash_id = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), timestamp).build();
+    /// assert_eq!(WasmiumHashID::validate(hash_id, min_time, max_time, &allowed), Ok(()));
+    ///
+    /// let unknown = WasmiumHashID::new_with_timestamp(blake3::hash(b"bar"), timestamp).build();
+    /// assert_eq!(
+    ///     WasmiumHashID::validate(unknown, min_time, max_time, &allowed),
+    ///     Err(Error::DigestNotAllowed)
+    /// );
+    ///
+    /// let stale = WasmiumHashID::new_with_timestamp(
+    ///     blake3::hash(b"foo"),
+    ///     timestamp - Duration::from_secs(120),
+    /// )
+    /// .build();
+    /// assert_eq!(
+    ///     WasmiumHashID::validate(stale, min_time, max_time, &allowed),
+    ///     Err(Error::OutOfRange)
+    /// );
+    ///
+    /// let future = WasmiumHashID::new_with_timestamp(
+    ///     blake3::hash(b"foo"),
+    ///     timestamp + Duration::from_secs(120),
+    /// )
+    /// .build();
+    /// assert_eq!(
+    ///     WasmiumHashID::validate(future, min_time, max_time, &allowed),
+    ///     Err(Error::OutOfRange)
+    /// );
+    ///
+    /// let mut invalid = hash_id;
+    /// invalid[8..12].copy_from_slice(&u32::MAX.to_be_bytes());
+    /// assert_eq!(
+    ///     WasmiumHashID::validate(invalid, min_time, max_time, &allowed),
+    ///     Err(Error::Timestamp(tai64::Error::NanosInvalid))
+    /// );
+    /// ```
+    pub fn validate(
+        hash_id: [u8; ID_LEN],
+        min_time: Tai64N,
+        max_time: Tai64N,
+        allowed_digests: &HashSet<[u8; HASH_LEN]>,
+    ) -> Result<(), Error> {
+        let timestamp = Tai64N::from_slice(&hash_id[..TIMESTAMP_LEN])?;
+
+        if timestamp < min_time || timestamp > max_time {
+            return Err(Error::OutOfRange);
+        }
+
+        let mut digest = [0u8; HASH_LEN];
+        digest.copy_from_slice(&hash_id[TIMESTAMP_LEN..]);
+
+        if allowed_digests.contains(&digest) {
+            Ok(())
+        } else {
+            Err(Error::DigestNotAllowed)
+        }
+    }
+
     /// Generate a blake3 hash from a byte array of 32 random bytes from CSPRNG
     ///
     /// //! #### Usage
@@ -162,10 +637,45 @@ impl WasmiumHashID {
         let mut rng = BufferedRng::new(ChaCha8::new());
         rng.fill(&mut buffer);
         let blake3hash = blake3::hash(&buffer);
-        WasmiumHashID {
+        let hash_id = WasmiumHashID {
             tai_timestamp: Tai64N::now().to_bytes(),
             blake3hash: *blake3hash.as_bytes(),
-        }
+        };
+        #[cfg(feature = "tracing")]
+        hash_id.trace("rand32");
+
+        hash_id
+    }
+
+    /// Generate a blake3 hash from a byte array of 32 random bytes from CSPRNG,
+    /// returning the random bytes alongside the `WasmiumHashID` so it can be verified later
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// // Generate the hash
+    /// let (hash_id, preimage) = WasmiumHashID::rand32_with_preimage();
+    ///
+    /// assert_eq!(
+    ///     WasmiumHashID::get_blake3_hash(hash_id.build()).unwrap(),
+    ///     blake3::hash(&preimage)
+    /// );
+    /// ```
+    #[cfg(feature = "random_id")]
+    pub fn rand32_with_preimage() -> (WasmiumHashID, [u8; 32]) {
+        let mut buffer = [0u8; 32];
+        let mut rng = BufferedRng::new(ChaCha8::new());
+        rng.fill(&mut buffer);
+        let blake3hash = blake3::hash(&buffer);
+        let hash_id = WasmiumHashID {
+            tai_timestamp: Tai64N::now().to_bytes(),
+            blake3hash: *blake3hash.as_bytes(),
+        };
+        #[cfg(feature = "tracing")]
+        hash_id.trace("rand32_with_preimage");
+
+        (hash_id, buffer)
     }
 
     /// Generate a blake3 hash from a byte array of 64 random bytes from CSPRNG
@@ -183,9 +693,154 @@ impl WasmiumHashID {
         let mut rng = BufferedRng::new(ChaCha8::new());
         rng.fill(&mut buffer);
         let blake3hash = blake3::hash(&buffer);
-        WasmiumHashID {
+        let hash_id = WasmiumHashID {
+            tai_timestamp: Tai64N::now().to_bytes(),
+            blake3hash: *blake3hash.as_bytes(),
+        };
+        #[cfg(feature = "tracing")]
+        hash_id.trace("rand64");
+
+        hash_id
+    }
+
+    /// Generate a `WasmiumHashID` for `message` stamped with the current TAI64N timestamp moved by
+    /// a uniformly random offset in `[-max_jitter, max_jitter]`, hiding the exact creation time
+    /// while keeping the approximate order. The offset is capped at about 292 years
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let max_jitter = Duration::from_secs(1);
+    ///
+    /// let before = Tai64N::now();
+    /// let ids = (0..5)
+    ///     .map(|_| WasmiumHashID::jittered(b"foo", max_jitter))
+    ///     .collect::<Vec<WasmiumHashID>>();
+    /// let after = Tai64N::now();
+    ///
+    /// for hash_id in &ids {
+    ///     let timestamp = WasmiumHashID::get_timestamp(hash_id.build()).unwrap();
+    ///     assert!(timestamp >= before - max_jitter && timestamp <= after + max_jitter);
+    ///     assert_eq!(hash_id.digest_bytes(), blake3::hash(b"foo").as_bytes());
+    /// }
+    /// assert!(ids.iter().any(|hash_id| hash_id.timestamp_bytes() != ids[0].timestamp_bytes()));
+    /// ```
+    #[cfg(feature = "random_id")]
+    pub fn jittered(message: &[u8], max_jitter: Duration) -> WasmiumHashID {
+        let max_nanos = max_jitter.as_nanos().min((u64::MAX / 2) as u128) as u64;
+        let offset = ChaCha8::new().generate_range(0..=max_nanos * 2);
+
+        let now = Tai64N::now();
+        let timestamp = if offset >= max_nanos {
+            now + Duration::from_nanos(offset - max_nanos)
+        } else {
+            now - Duration::from_nanos(max_nanos - offset)
+        };
+
+        let hash_id = WasmiumHashID {
+            tai_timestamp: timestamp.to_bytes(),
+            blake3hash: *blake3::hash(message).as_bytes(),
+        };
+        #[cfg(feature = "tracing")]
+        hash_id.trace("jittered");
+
+        hash_id
+    }
+
+    /// Lazily generate random `WasmiumHashID`s forever like `rand32`, reusing one
+    /// ChaCha8 RNG for the whole stream instead of seeding one per ID
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let digests = WasmiumHashID::random_stream()
+    ///     .take(100)
+    ///     .map(|hash_id| *hash_id.digest_bytes())
+    ///     .collect::<HashSet<[u8; 32]>>();
+    ///
+    /// assert_eq!(digests.len(), 100);
+    /// ```
+    #[cfg(feature = "random_id")]
+    pub fn random_stream() -> impl Iterator<Item = WasmiumHashID> {
+        let mut rng = BufferedRng::new(ChaCha8::new());
+
+        std::iter::repeat_with(move || {
+            let mut buffer = [0u8; 32];
+            rng.fill(&mut buffer);
+            let hash_id = WasmiumHashID {
+                tai_timestamp: Tai64N::now().to_bytes(),
+                blake3hash: *blake3::hash(&buffer).as_bytes(),
+            };
+            #[cfg(feature = "tracing")]
+            hash_id.trace("random_stream");
+
+            hash_id
+        })
+    }
+
+    /// Generate a blake3 hash from `len` random bytes read directly from the operating system CSPRNG
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// // Generate the hash
+    /// let first = WasmiumHashID::rand_os(32).unwrap();
+    /// let second = WasmiumHashID::rand_os(32).unwrap();
+    ///
+    /// assert_ne!(first.build()[12..], second.build()[12..]);
+    /// ```
+    #[cfg(feature = "getrandom")]
+    pub fn rand_os(len: usize) -> std::io::Result<WasmiumHashID> {
+        let mut buffer = vec![0u8; len];
+        getrandom::getrandom(&mut buffer)?;
+        let blake3hash = blake3::hash(&buffer);
+        let hash_id = WasmiumHashID {
             tai_timestamp: Tai64N::now().to_bytes(),
             blake3hash: *blake3hash.as_bytes(),
+        };
+        #[cfg(feature = "tracing")]
+        hash_id.trace("rand_os");
+
+        Ok(hash_id)
+    }
+
+    /// Emit a `TRACE` event describing this `WasmiumHashID`
+    #[cfg(feature = "tracing")]
+    fn trace(&self, operation: &'static str) {
+        let digest = blake3::Hash::from(self.blake3hash).to_hex();
+        let mut seconds = [0u8; 8];
+        seconds.copy_from_slice(&self.tai_timestamp[..8]);
+        let mut nanos = [0u8; 4];
+        nanos.copy_from_slice(&self.tai_timestamp[8..]);
+
+        tracing::trace!(
+            operation,
+            digest = &digest[..8],
+            tai64_seconds = u64::from_be_bytes(seconds),
+            tai64_nanos = u32::from_be_bytes(nanos),
+            "WasmiumHashID"
+        );
+    }
+
+    /// Parse a `WasmiumHashID` from a byte slice of `ID_LEN` bytes, validating the TAI64N timestamp
+    pub(crate) fn from_slice(bytes: &[u8]) -> Result<WasmiumHashID, Error> {
+        if bytes.len() != ID_LEN {
+            return Err(Error::InvalidLength(bytes.len()));
         }
+
+        let tai_timestamp = Tai64N::from_slice(&bytes[..TIMESTAMP_LEN])?.to_bytes();
+        let mut blake3hash = [0u8; HASH_LEN];
+        blake3hash.copy_from_slice(&bytes[TIMESTAMP_LEN..]);
+
+        Ok(WasmiumHashID {
+            tai_timestamp,
+            blake3hash,
+        })
     }
 }
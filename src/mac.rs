@@ -0,0 +1,45 @@
+use crate::{WasmiumHashID, HASH_LEN};
+
+impl WasmiumHashID {
+    /// Compute an authentication tag over the built `WasmiumHashID` using
+    /// `blake3::keyed_hash`, so the ID itself can be authenticated when it is transmitted
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let key = [7u8; 32];
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    ///
+    /// assert_eq!(
+    ///     &hash_id.mac(&key),
+    ///     blake3::keyed_hash(&key, &hash_id.build()).as_bytes()
+    /// );
+    /// ```
+    pub fn mac(&self, key: &[u8; 32]) -> [u8; HASH_LEN] {
+        *blake3::keyed_hash(key, &self.build()).as_bytes()
+    }
+
+    /// Check an authentication tag computed by `mac`, comparing the tags in constant time
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use borsh::BorshDeserialize;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let key = [7u8; 32];
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let tag = hash_id.mac(&key);
+    /// assert!(hash_id.verify_mac(&key, &tag));
+    /// assert!(!hash_id.verify_mac(&[8u8; 32], &tag));
+    ///
+    /// let mut tampered = hash_id.build();
+    /// tampered[43] ^= 1;
+    /// let tampered = WasmiumHashID::try_from_slice(&tampered).unwrap();
+    /// assert!(!tampered.verify_mac(&key, &tag));
+    /// ```
+    pub fn verify_mac(&self, key: &[u8; 32], tag: &[u8; HASH_LEN]) -> bool {
+        // `blake3::Hash` implements `PartialEq` in constant time
+        blake3::keyed_hash(key, &self.build()) == blake3::Hash::from(*tag)
+    }
+}
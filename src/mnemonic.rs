@@ -0,0 +1,115 @@
+use bip39::Language;
+
+use crate::{Error, WasmiumHashID, ID_LEN};
+
+/// The number of bits each mnemonic word encodes
+const BITS_PER_WORD: usize = 11;
+
+/// The number of checksum bits appended to the 352 bits of a `WasmiumHashID`, one per 32 bits
+const CHECKSUM_BITS: usize = ID_LEN * 8 / 32;
+
+/// The number of words in the mnemonic of a `WasmiumHashID`
+const MNEMONIC_WORDS: usize = (ID_LEN * 8 + CHECKSUM_BITS) / BITS_PER_WORD;
+
+/// Get the checksum of the built `WasmiumHashID`, the leading `CHECKSUM_BITS` of its Blake3 hash
+fn checksum(bytes: &[u8; ID_LEN]) -> u16 {
+    let hash = blake3::hash(bytes);
+    let leading = u16::from_be_bytes([hash.as_bytes()[0], hash.as_bytes()[1]]);
+
+    leading >> (16 - CHECKSUM_BITS)
+}
+
+impl WasmiumHashID {
+    /// Encode the `WasmiumHashID` as 33 words from the English BIP39 wordlist for human
+    /// transcription. Like BIP39 the 352 bits are followed by an 11 bit checksum and split
+    /// into 11 bit word indices, but the checksum is taken from the Blake3 hash of the ID
+    /// rather than SHA-256, so the words are not a BIP39 seed phrase
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// let words = hash_id.to_mnemonic();
+    ///
+    /// assert_eq!(words.len(), 33);
+    /// assert_eq!(WasmiumHashID::from_mnemonic(&words), Ok(hash_id));
+    /// ```
+    pub fn to_mnemonic(&self) -> Vec<String> {
+        let bytes = self.build();
+        let word_list = Language::English.word_list();
+
+        let mut words = Vec::with_capacity(MNEMONIC_WORDS);
+        let mut buffer = 0u32;
+        let mut bits = 0;
+
+        let checksum = checksum(&bytes);
+        let chunks = bytes
+            .iter()
+            .map(|byte| (*byte as u32, 8))
+            .chain(core::iter::once((checksum as u32, CHECKSUM_BITS)));
+
+        for (value, width) in chunks {
+            buffer = (buffer << width) | value;
+            bits += width;
+
+            while bits >= BITS_PER_WORD {
+                bits -= BITS_PER_WORD;
+                let index = (buffer >> bits) as usize & ((1 << BITS_PER_WORD) - 1);
+                words.push(word_list[index].to_string());
+            }
+        }
+
+        words
+    }
+
+    /// Decode a `WasmiumHashID` from the 33 words built by `to_mnemonic`, verifying the
+    /// checksum and the TAI64N timestamp. Unknown words are reported as `Error::InvalidEncoding`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), Tai64N::UNIX_EPOCH);
+    /// let mut words = hash_id.to_mnemonic();
+    ///
+    /// assert_eq!(WasmiumHashID::from_mnemonic(&words[..32]), Err(Error::InvalidLength(32)));
+    ///
+    /// words[20] = if words[20] == "abandon" { "ability" } else { "abandon" }.to_string();
+    /// assert_eq!(WasmiumHashID::from_mnemonic(&words), Err(Error::InvalidChecksum));
+    ///
+    /// words[20] = "wasmium".to_string();
+    /// assert_eq!(WasmiumHashID::from_mnemonic(&words), Err(Error::InvalidEncoding));
+    /// ```
+    pub fn from_mnemonic<S: AsRef<str>>(words: &[S]) -> Result<WasmiumHashID, Error> {
+        if words.len() != MNEMONIC_WORDS {
+            return Err(Error::InvalidLength(words.len()));
+        }
+
+        let mut bytes = [0u8; ID_LEN];
+        let mut position = 0;
+        let mut buffer = 0u32;
+        let mut bits = 0;
+
+        for word in words {
+            let index = Language::English
+                .find_word(word.as_ref())
+                .ok_or(Error::InvalidEncoding)?;
+            buffer = (buffer << BITS_PER_WORD) | index as u32;
+            bits += BITS_PER_WORD;
+
+            while bits >= 8 && position < ID_LEN {
+                bits -= 8;
+                bytes[position] = (buffer >> bits) as u8;
+                position += 1;
+            }
+        }
+
+        if buffer & ((1 << CHECKSUM_BITS) - 1) != checksum(&bytes) as u32 {
+            return Err(Error::InvalidChecksum);
+        }
+
+        WasmiumHashID::from_slice(&bytes)
+    }
+}
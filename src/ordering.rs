@@ -0,0 +1,126 @@
+use core::cmp::Ordering;
+
+use crate::{WasmiumHashID, HASH_LEN, TIMESTAMP_LEN};
+
+impl WasmiumHashID {
+    /// Compare two `WasmiumHashID`s by their Blake3 hash first and their TAI64N timestamp second,
+    /// the inverse of the default `Ord` which is useful for content addressed indexes
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let timestamp = Tai64N::UNIX_EPOCH + Duration::from_secs(1_704_164_645);
+    /// let older = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), timestamp);
+    /// let newer = WasmiumHashID::new_with_timestamp(
+    ///     blake3::hash(b"foo"),
+    ///     timestamp + Duration::from_nanos(1),
+    /// );
+    ///
+    /// assert_eq!(WasmiumHashID::cmp_by_content(&older, &newer), Ordering::Less);
+    /// assert_eq!(WasmiumHashID::cmp_by_content(&older, &older), Ordering::Equal);
+    /// ```
+    pub fn cmp_by_content(a: &Self, b: &Self) -> Ordering {
+        a.blake3hash
+            .cmp(&b.blake3hash)
+            .then_with(|| a.tai_timestamp.cmp(&b.tai_timestamp))
+    }
+
+    /// Sort a slice of `WasmiumHashID`s using `cmp_by_content`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let timestamp = Tai64N::UNIX_EPOCH + Duration::from_secs(1_704_164_645);
+    /// let mut ids = [b"foo", b"bar", b"baz", b"foo"]
+    ///     .iter()
+    ///     .zip(0..)
+    ///     .map(|(message, offset)| {
+    ///         WasmiumHashID::new_with_timestamp(
+    ///             blake3::hash(*message),
+    ///             timestamp + Duration::from_nanos(offset),
+    ///         )
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let first_foo = ids[0].clone();
+    /// let second_foo = ids[3].clone();
+    ///
+    /// WasmiumHashID::sort_by_content(&mut ids);
+    ///
+    /// let position = ids.iter().position(|id| id == &first_foo).unwrap();
+    /// assert_eq!(ids[position + 1], second_foo);
+    /// ```
+    pub fn sort_by_content(ids: &mut [WasmiumHashID]) {
+        ids.sort_by(WasmiumHashID::cmp_by_content);
+    }
+
+    /// Check that a slice of `WasmiumHashID`s is strictly increasing by the default `Ord`,
+    /// so sorted and free of duplicates. The error holds the index of the first entry
+    /// that is not greater than the one before it
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let timestamp = Tai64N::UNIX_EPOCH + Duration::from_secs(1_704_164_645);
+    /// let first = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), timestamp);
+    /// let second = WasmiumHashID::new_with_timestamp(
+    ///     blake3::hash(b"bar"),
+    ///     timestamp + Duration::from_nanos(1),
+    /// );
+    /// let third = WasmiumHashID::new_with_timestamp(
+    ///     blake3::hash(b"baz"),
+    ///     timestamp + Duration::from_nanos(2),
+    /// );
+    ///
+    /// let sorted = [first.clone(), second.clone(), third.clone()];
+    /// assert_eq!(WasmiumHashID::check_sorted_unique(&sorted), Ok(()));
+    /// assert_eq!(WasmiumHashID::check_sorted_unique(&[]), Ok(()));
+    ///
+    /// let out_of_order = [first.clone(), third.clone(), second.clone()];
+    /// assert_eq!(WasmiumHashID::check_sorted_unique(&out_of_order), Err(2));
+    ///
+    /// let duplicate = [first.clone(), first, second];
+    /// assert_eq!(WasmiumHashID::check_sorted_unique(&duplicate), Err(1));
+    /// ```
+    pub fn check_sorted_unique(ids: &[WasmiumHashID]) -> Result<(), usize> {
+        match ids.windows(2).position(|pair| pair[0] >= pair[1]) {
+            Some(index) => Err(index + 1),
+            None => Ok(()),
+        }
+    }
+
+    /// Get the TAI64N timestamp and the Blake3 hash as a tuple for keyset pagination,
+    /// such as `WHERE (timestamp, digest) > (?, ?)`. Tuples compare in the same order as
+    /// the default `Ord`, timestamp first with the Blake3 hash as the tie breaker
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let timestamp = tai64::Tai64N::now();
+    /// let mut ids = vec![
+    ///     WasmiumHashID::new(blake3::hash(b"foo")),
+    ///     WasmiumHashID::new_with_timestamp(blake3::hash(b"bar"), timestamp),
+    ///     WasmiumHashID::new_with_timestamp(blake3::hash(b"baz"), timestamp),
+    ///     WasmiumHashID::new(blake3::hash(b"qux")),
+    /// ];
+    ///
+    /// let mut by_key = ids.clone();
+    /// by_key.sort_by_key(WasmiumHashID::pagination_key);
+    /// ids.sort();
+    ///
+    /// assert_eq!(by_key, ids);
+    /// ```
+    pub fn pagination_key(&self) -> ([u8; TIMESTAMP_LEN], [u8; HASH_LEN]) {
+        (self.tai_timestamp, self.blake3hash)
+    }
+}
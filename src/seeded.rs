@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use tai64::Tai64N;
+
+use crate::WasmiumHashID;
+
+/// The Blake3 key derivation context for the seeded random byte stream
+const SEEDED_CONTEXT: &str = "wasmium-hash-id seeded random bytes v1";
+
+/// The largest index accepted by `replay_seeded`, the last 32 byte block before the
+/// `u64` position of the Blake3 extendable output would overflow
+const MAX_SEEDED_INDEX: u64 = u64::MAX / 32 - 1;
+
+/// Generate reproducible `WasmiumHashID`s from a `u64` seed. The random bytes come from the
+/// Blake3 extendable output keyed by the seed, so the bytes of any call can be recomputed
+/// with `WasmiumHashID::replay_seeded` without replaying the calls before it.
+/// This is meant for audits and tests, not as a replacement for `rand32` since a 64 bit seed can be brute forced
+///
+/// #### Usage
+/// ```rust
+/// use wasmium_hash_id::WasmiumHashIDSeeded;
+///
+/// let mut first = WasmiumHashIDSeeded::new(42);
+/// let mut second = WasmiumHashIDSeeded::new(42);
+///
+/// assert_eq!(first.next().unwrap().digest_bytes(), second.next().unwrap().digest_bytes());
+/// assert_ne!(
+///     first.next().unwrap().digest_bytes(),
+///     WasmiumHashIDSeeded::new(43).next().unwrap().digest_bytes()
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct WasmiumHashIDSeeded {
+    seed: u64,
+    index: usize,
+}
+
+impl WasmiumHashIDSeeded {
+    /// Create a new seeded generator starting at index zero
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashIDSeeded;
+    ///
+    /// let generator = WasmiumHashIDSeeded::new(42);
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        WasmiumHashIDSeeded { seed, index: 0 }
+    }
+}
+
+impl Iterator for WasmiumHashIDSeeded {
+    type Item = WasmiumHashID;
+
+    /// Generate a `WasmiumHashID` from the Blake3 hash of the next 32 random bytes of the stream,
+    /// ending once the stream is exhausted at index `u64::MAX / 32 - 1`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{WasmiumHashID, WasmiumHashIDSeeded};
+    ///
+    /// let mut generator = WasmiumHashIDSeeded::new(42);
+    /// let hash_id = generator.next().unwrap();
+    ///
+    /// assert_eq!(
+    ///     hash_id.digest_bytes(),
+    ///     blake3::hash(&WasmiumHashID::replay_seeded(42, 0)).as_bytes()
+    /// );
+    /// ```
+    fn next(&mut self) -> Option<WasmiumHashID> {
+        if u64::try_from(self.index).map_or(true, |index| index > MAX_SEEDED_INDEX) {
+            return None;
+        }
+
+        let random = WasmiumHashID::replay_seeded(self.seed, self.index);
+        self.index += 1;
+
+        Some(WasmiumHashID::new(blake3::hash(&random)))
+    }
+}
+
+impl WasmiumHashID {
+    /// Get the 32 random bytes the `index`th call to `next` on a `WasmiumHashIDSeeded`
+    /// produces for `seed`, so an audit can reproduce a generated sequence.
+    /// Panics if `index` is above `u64::MAX / 32 - 1`, past the end of the stream
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{WasmiumHashID, WasmiumHashIDSeeded};
+    ///
+    /// let ids = WasmiumHashIDSeeded::new(7).take(5).collect::<Vec<WasmiumHashID>>();
+    ///
+    /// let replayed = WasmiumHashID::replay_seeded(7, 3);
+    /// assert_eq!(ids[3].digest_bytes(), blake3::hash(&replayed).as_bytes());
+    /// assert_ne!(replayed, WasmiumHashID::replay_seeded(7, 4));
+    /// ```
+    ///
+    /// An index past the end of the stream panics
+    /// ```rust,should_panic
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// WasmiumHashID::replay_seeded(7, usize::MAX);
+    /// ```
+    pub fn replay_seeded(seed: u64, index: usize) -> [u8; 32] {
+        let position = u64::try_from(index)
+            .ok()
+            .filter(|index| *index <= MAX_SEEDED_INDEX)
+            .map(|index| index * 32)
+            .expect("index is past the end of the seeded stream");
+
+        let mut hasher = blake3::Hasher::new_derive_key(SEEDED_CONTEXT);
+        hasher.update(&seed.to_be_bytes());
+
+        let mut random = [0u8; 32];
+        let mut reader = hasher.finalize_xof();
+        reader.set_position(position);
+        reader.fill(&mut random);
+
+        random
+    }
+
+    /// Generate a reproducible corpus of `count` IDs for load testing. The Blake3 hashes are
+    /// those of a `WasmiumHashIDSeeded` for `seed` and the synthetic TAI64N timestamps start at
+    /// the Unix epoch and increase by one millisecond per ID, so the output depends only on the inputs
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{WasmiumHashID, WasmiumHashIDSeeded};
+    ///
+    /// let ids = WasmiumHashID::sequence(42, 100);
+    /// assert_eq!(ids, WasmiumHashID::sequence(42, 100));
+    /// assert_ne!(ids, WasmiumHashID::sequence(43, 100));
+    /// assert_eq!(WasmiumHashID::check_sorted_unique(&ids), Ok(()));
+    ///
+    /// let seeded = WasmiumHashIDSeeded::new(42).next().unwrap();
+    /// assert_eq!(ids[0].digest_bytes(), seeded.digest_bytes());
+    /// ```
+    pub fn sequence(seed: u64, count: usize) -> Vec<WasmiumHashID> {
+        (0..count)
+            .map(|index| {
+                WasmiumHashID::new_with_timestamp(
+                    blake3::hash(&WasmiumHashID::replay_seeded(seed, index)),
+                    Tai64N::UNIX_EPOCH + Duration::from_millis(index as u64),
+                )
+            })
+            .collect()
+    }
+}
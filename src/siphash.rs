@@ -0,0 +1,22 @@
+use siphasher::sip::SipHasher;
+
+use crate::WasmiumHashID;
+
+impl WasmiumHashID {
+    /// Compute a keyed SipHash-2-4 of the built `WasmiumHashID` for hash maps that must resist
+    /// flooding when attackers control the Blake3 hashes. Use a random key per process,
+    /// the output is only as unpredictable as `k0` and `k1`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    ///
+    /// assert_eq!(hash_id.siphash_key(1, 2), hash_id.siphash_key(1, 2));
+    /// assert_ne!(hash_id.siphash_key(1, 2), hash_id.siphash_key(3, 4));
+    /// ```
+    pub fn siphash_key(&self, k0: u64, k1: u64) -> u64 {
+        SipHasher::new_with_keys(k0, k1).hash(&self.build())
+    }
+}
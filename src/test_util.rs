@@ -0,0 +1,49 @@
+use crate::{Error, WasmiumHashID};
+
+impl WasmiumHashID {
+    /// Assert that encoding with `encode` and decoding the output with `decode` gives back an
+    /// equal `WasmiumHashID`, so downstream crates can test that their serialization layer
+    /// preserves IDs. Panics with the ID, the encoded bytes and the decode result otherwise
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    ///
+    /// hash_id.assert_roundtrip(
+    ///     |id| id.to_hex().into_bytes(),
+    ///     |bytes| {
+    ///         let hex = core::str::from_utf8(bytes).map_err(|_| Error::InvalidEncoding)?;
+    ///         WasmiumHashID::parse_hex_into(hex)
+    ///     },
+    /// );
+    /// ```
+    ///
+    /// A lossy encoding panics
+    /// ```rust,should_panic
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// WasmiumHashID::new(blake3::hash(b"foo")).assert_roundtrip(
+    ///     |id| id.build()[..43].to_vec(),
+    ///     |bytes| WasmiumHashID::read_batch(bytes).map(|mut ids| ids.remove(0)),
+    /// );
+    /// ```
+    pub fn assert_roundtrip(
+        &self,
+        encode: impl Fn(&Self) -> Vec<u8>,
+        decode: impl Fn(&[u8]) -> Result<Self, Error>,
+    ) {
+        let encoded = encode(self);
+        let decoded = decode(&encoded);
+
+        if decoded.as_ref() != Ok(self) {
+            panic!(
+                "round trip of WasmiumHashID {} failed\n  encoded: {:02x?}\n  decoded: {:?}",
+                self.to_hex(),
+                encoded,
+                decoded
+            );
+        }
+    }
+}
@@ -0,0 +1,647 @@
+use std::time::Duration;
+
+use tai64::{Tai64, Tai64N};
+
+use crate::{Error, WasmiumHashID, ID_LEN, TIMESTAMP_LEN};
+
+/// The TAI64 label of `1970-01-01T00:00:00Z` in true TAI, when TAI - UTC was 10 seconds
+const TAI_UNIX_EPOCH: u64 = (1 << 62) + 10;
+
+/// The Unix time of the UTC midnight that follows each leap second inserted since 1972,
+/// from the IERS leap second list. The `n`th entry was the `n`th second added to TAI - UTC
+const LEAP_SECONDS: [u64; 27] = [
+    78_796_800,
+    94_694_400,
+    126_230_400,
+    157_766_400,
+    189_302_400,
+    220_924_800,
+    252_460_800,
+    283_996_800,
+    315_532_800,
+    362_793_600,
+    394_329_600,
+    425_865_600,
+    489_024_000,
+    567_993_600,
+    631_152_000,
+    662_688_000,
+    709_948_800,
+    741_484_800,
+    773_020_800,
+    820_454_400,
+    867_715_200,
+    915_148_800,
+    1_136_073_600,
+    1_230_768_000,
+    1_341_100_800,
+    1_435_708_800,
+    1_483_228_800,
+];
+
+impl WasmiumHashID {
+    /// Decode the TAI64N timestamp of this `WasmiumHashID`
+    pub(crate) fn tai64n(&self) -> Result<Tai64N, Error> {
+        Ok(Tai64N::from_slice(&self.tai_timestamp)?)
+    }
+
+    /// Get the milliseconds since the Unix epoch of the raw TAI64N timestamp without validating it,
+    /// saturating at zero before the epoch and at `2^48 - 1`, the largest value of the
+    /// 48 bit timestamps used by UUIDv7 style UUIDs and ULIDs, so ordering never wraps
+    #[cfg(any(feature = "ulid", feature = "uuid"))]
+    pub(crate) fn unix_millis48(&self) -> u64 {
+        let mut seconds = [0u8; 8];
+        seconds.copy_from_slice(&self.tai_timestamp[..8]);
+        let mut nanos = [0u8; 4];
+        nanos.copy_from_slice(&self.tai_timestamp[8..]);
+
+        let seconds = u64::from_be_bytes(seconds).saturating_sub(Tai64::UNIX_EPOCH.0);
+        seconds
+            .saturating_mul(1000)
+            .saturating_add((u32::from_be_bytes(nanos) / 1_000_000).min(999) as u64)
+            .min((1 << 48) - 1)
+    }
+
+    /// Get the leading `prefix_len` bytes of the TAI64N timestamp to use as a shard key.
+    /// `prefix_len` is clamped to `TIMESTAMP_LEN`, shorter prefixes give coarser time buckets
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let first = WasmiumHashID::new(blake3::hash(b"foo")).build();
+    /// let second = WasmiumHashID::new(blake3::hash(b"bar")).build();
+    ///
+    /// // The first 4 bytes only change every 2^32 seconds
+    /// assert_eq!(
+    ///     WasmiumHashID::time_bucket(&first, 4),
+    ///     WasmiumHashID::time_bucket(&second, 4)
+    /// );
+    /// assert_eq!(WasmiumHashID::time_bucket(&first, 100).len(), 12);
+    /// ```
+    pub fn time_bucket(hash_id: &[u8; ID_LEN], prefix_len: usize) -> &[u8] {
+        &hash_id[..prefix_len.min(TIMESTAMP_LEN)]
+    }
+
+    /// Get the raw TAI64 second count and the nanosecond subfield of the TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use tai64::{Tai64, Tai64N};
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let mut hash_id = WasmiumHashID::new(blake3::hash(b"foo")).build();
+    /// hash_id[..12].copy_from_slice(&Tai64N(Tai64(0x4000_0000_6000_0000), 123_456_789).to_bytes());
+    ///
+    /// assert_eq!(
+    ///     WasmiumHashID::timestamp_components(hash_id),
+    ///     Ok((0x4000_0000_6000_0000, 123_456_789))
+    /// );
+    /// ```
+    pub fn timestamp_components(hash_id: [u8; ID_LEN]) -> Result<(u64, u32), Error> {
+        let timestamp = Tai64N::from_slice(&hash_id[..TIMESTAMP_LEN])?;
+
+        Ok((timestamp.0 .0, timestamp.1))
+    }
+
+    /// Generate a `WasmiumHashID` for `message` recorded at `unix_seconds` seconds since the
+    /// Unix epoch, for backfilling historical data. Negative values are before 1970 and
+    /// the TAI64 label saturates at zero for values too far in the past to represent
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::{Duration, SystemTime};
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::historical(1_000_000_000, b"foo");
+    /// let timestamp = WasmiumHashID::get_timestamp(hash_id.build()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     timestamp.to_system_time(),
+    ///     SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000)
+    /// );
+    /// assert_eq!(hash_id.digest_bytes(), blake3::hash(b"foo").as_bytes());
+    ///
+    /// let before_1970 = WasmiumHashID::historical(-86_400, b"foo");
+    /// assert!(before_1970 < WasmiumHashID::historical(0, b"foo"));
+    /// ```
+    pub fn historical(unix_seconds: i64, message: &[u8]) -> WasmiumHashID {
+        let label = Tai64N::UNIX_EPOCH.0 .0.saturating_add_signed(unix_seconds);
+
+        WasmiumHashID::new_with_timestamp(blake3::hash(message), Tai64N(Tai64(label), 0))
+    }
+
+    /// Find the indices of `WasmiumHashID`s whose TAI64N timestamp is earlier than the one
+    /// before it, which in an append only log points to clock skew
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let start = Tai64N::now();
+    /// let ids = [0u64, 1, 2, 1, 3, 4]
+    ///     .iter()
+    ///     .map(|seconds| {
+    ///         WasmiumHashID::new_with_timestamp(
+    ///             blake3::hash(b"foo"),
+    ///             start + Duration::from_secs(*seconds),
+    ///         )
+    ///     })
+    ///     .collect::<Vec<WasmiumHashID>>();
+    ///
+    /// assert_eq!(WasmiumHashID::find_time_regressions(&ids), vec![3]);
+    /// assert!(WasmiumHashID::find_time_regressions(&ids[..3]).is_empty());
+    /// ```
+    pub fn find_time_regressions(ids: &[WasmiumHashID]) -> Vec<usize> {
+        ids.windows(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[1].tai_timestamp < pair[0].tai_timestamp)
+            .map(|(index, _)| index + 1)
+            .collect()
+    }
+
+    /// Get the duration between the earliest and the latest TAI64N timestamp in a batch,
+    /// zero for an empty batch. Errors if any of the timestamps is invalid
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let start = Tai64N::now();
+    /// let batch = |offsets: &[u64]| {
+    ///     offsets
+    ///         .iter()
+    ///         .map(|millis| {
+    ///             WasmiumHashID::new_with_timestamp(
+    ///                 blake3::hash(b"foo"),
+    ///                 start + Duration::from_millis(*millis),
+    ///             )
+    ///         })
+    ///         .collect::<Vec<WasmiumHashID>>()
+    /// };
+    ///
+    /// assert_eq!(WasmiumHashID::max_spread(&batch(&[5, 0, 3])), Ok(Duration::from_millis(5)));
+    /// assert_eq!(
+    ///     WasmiumHashID::max_spread(&batch(&[0, 3_600_000, 10])),
+    ///     Ok(Duration::from_secs(3600))
+    /// );
+    /// assert_eq!(WasmiumHashID::max_spread(&[]), Ok(Duration::ZERO));
+    /// ```
+    pub fn max_spread(ids: &[WasmiumHashID]) -> Result<Duration, Error> {
+        let timestamps = ids
+            .iter()
+            .map(WasmiumHashID::tai64n)
+            .collect::<Result<Vec<Tai64N>, Error>>()?;
+
+        match (timestamps.iter().min(), timestamps.iter().max()) {
+            (Some(earliest), Some(latest)) => {
+                Ok(latest.duration_since(earliest).unwrap_or_default())
+            }
+            _ => Ok(Duration::ZERO),
+        }
+    }
+
+    /// Check whether two `WasmiumHashID` byte arrays have the same Blake3 hash and the same
+    /// TAI64 seconds label, ignoring the nanosecond subfield for deduplication at second
+    /// granularity. Errors if either timestamp is invalid
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use tai64::{Tai64, Tai64N};
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let seconds = Tai64N::now().0;
+    /// let first = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), Tai64N(seconds, 1)).build();
+    /// let second =
+    ///     WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), Tai64N(seconds, 999_999_999)).build();
+    /// assert_eq!(WasmiumHashID::eq_second_resolution(first, second), Ok(true));
+    ///
+    /// let next_second =
+    ///     WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), Tai64N(seconds + 1, 1)).build();
+    /// assert_eq!(WasmiumHashID::eq_second_resolution(first, next_second), Ok(false));
+    ///
+    /// let other = WasmiumHashID::new_with_timestamp(blake3::hash(b"bar"), Tai64N(seconds, 1)).build();
+    /// assert_eq!(WasmiumHashID::eq_second_resolution(first, other), Ok(false));
+    /// ```
+    pub fn eq_second_resolution(a: [u8; ID_LEN], b: [u8; ID_LEN]) -> Result<bool, Error> {
+        let a_timestamp = Tai64N::from_slice(&a[..TIMESTAMP_LEN])?;
+        let b_timestamp = Tai64N::from_slice(&b[..TIMESTAMP_LEN])?;
+
+        Ok(a_timestamp.0 == b_timestamp.0 && a[TIMESTAMP_LEN..] == b[TIMESTAMP_LEN..])
+    }
+
+    /// Get only the nanosecond subfield of the TAI64N timestamp, reading the 4 bytes directly
+    /// without decoding the seconds label
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use tai64::{Tai64, Tai64N};
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let mut hash_id = WasmiumHashID::new(blake3::hash(b"foo")).build();
+    /// hash_id[..12].copy_from_slice(&Tai64N(Tai64(0x4000_0000_6000_0000), 123_456_789).to_bytes());
+    /// assert_eq!(WasmiumHashID::timestamp_nanos(hash_id), Ok(123_456_789));
+    ///
+    /// hash_id[8..12].copy_from_slice(&1_000_000_000u32.to_be_bytes());
+    /// assert_eq!(
+    ///     WasmiumHashID::timestamp_nanos(hash_id),
+    ///     Err(Error::Timestamp(tai64::Error::NanosInvalid))
+    /// );
+    /// ```
+    pub fn timestamp_nanos(hash_id: [u8; ID_LEN]) -> Result<u32, Error> {
+        let mut nanos = [0u8; 4];
+        nanos.copy_from_slice(&hash_id[8..TIMESTAMP_LEN]);
+        let nanos = u32::from_be_bytes(nanos);
+
+        if nanos > 999_999_999 {
+            return Err(Error::Timestamp(tai64::Error::NanosInvalid));
+        }
+
+        Ok(nanos)
+    }
+
+    /// Check that the nanosecond subfield of the TAI64N timestamp does not exceed 999,999,999.
+    /// IDs decoded through the derived `BorshDeserialize` are not validated and may not be canonical
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use borsh::BorshDeserialize;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    /// assert!(hash_id.is_canonical());
+    ///
+    /// let mut bytes = hash_id.build();
+    /// bytes[8..12].copy_from_slice(&1_000_000_000u32.to_be_bytes());
+    /// let malformed = WasmiumHashID::try_from_slice(&bytes).unwrap();
+    /// assert!(!malformed.is_canonical());
+    /// ```
+    pub fn is_canonical(&self) -> bool {
+        Tai64N::from_slice(&self.tai_timestamp).is_ok()
+    }
+
+    /// Get the TAI64N timestamp as whole seconds since the Unix epoch,
+    /// using the same epoch as `Tai64N::now()` so no `tai64` types are needed by the caller.
+    /// Errors with `Error::OutOfRange` if the seconds do not fit an `i64`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    /// use tai64::{Tai64, Tai64N};
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let mut hash_id = WasmiumHashID::new(blake3::hash(b"foo")).build();
+    /// hash_id[..12].copy_from_slice(&(Tai64N::UNIX_EPOCH + Duration::new(1_700_000_000, 5)).to_bytes());
+    /// assert_eq!(WasmiumHashID::timestamp_unix_seconds(hash_id), Ok(1_700_000_000));
+    ///
+    /// let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo")).build();
+    /// assert!((WasmiumHashID::timestamp_unix_seconds(hash_id).unwrap() - now).abs() <= 1);
+    ///
+    /// let far_future = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), Tai64N(Tai64(u64::MAX), 0));
+    /// assert_eq!(WasmiumHashID::timestamp_unix_seconds(far_future.build()), Err(Error::OutOfRange));
+    /// ```
+    pub fn timestamp_unix_seconds(hash_id: [u8; ID_LEN]) -> Result<i64, Error> {
+        let Tai64N(Tai64(seconds), _) = Tai64N::from_slice(&hash_id[..TIMESTAMP_LEN])?;
+        let Tai64(unix_epoch) = Tai64::UNIX_EPOCH;
+
+        i64::try_from(seconds as i128 - unix_epoch as i128).map_err(|_| Error::OutOfRange)
+    }
+
+    /// Check whether the TAI64N timestamp of the `WasmiumHashID` is strictly after `reference`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let earlier = Tai64N::now() - Duration::from_secs(60);
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo")).build();
+    /// let later = Tai64N::now() + Duration::from_secs(60);
+    ///
+    /// assert_eq!(WasmiumHashID::is_after(hash_id, earlier), Ok(true));
+    /// assert_eq!(WasmiumHashID::is_after(hash_id, later), Ok(false));
+    /// ```
+    pub fn is_after(hash_id: [u8; ID_LEN], reference: Tai64N) -> Result<bool, Error> {
+        Ok(Tai64N::from_slice(&hash_id[..TIMESTAMP_LEN])? > reference)
+    }
+
+    /// Check whether the TAI64N timestamp of the `WasmiumHashID` is strictly before `reference`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let earlier = Tai64N::now() - Duration::from_secs(60);
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo")).build();
+    /// let later = Tai64N::now() + Duration::from_secs(60);
+    ///
+    /// assert_eq!(WasmiumHashID::is_before(hash_id, later), Ok(true));
+    /// assert_eq!(WasmiumHashID::is_before(hash_id, earlier), Ok(false));
+    /// ```
+    pub fn is_before(hash_id: [u8; ID_LEN], reference: Tai64N) -> Result<bool, Error> {
+        Ok(Tai64N::from_slice(&hash_id[..TIMESTAMP_LEN])? < reference)
+    }
+
+    /// Check whether the TAI64N timestamp of the `WasmiumHashID` is strictly before an audit
+    /// `checkpoint`, an ID stamped at the checkpoint itself does not predate it
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let checkpoint = Tai64N::now();
+    /// let at = |timestamp| WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), timestamp).build();
+    ///
+    /// let before = at(checkpoint - Duration::from_nanos(1));
+    /// let after = at(checkpoint + Duration::from_secs(1));
+    ///
+    /// assert_eq!(WasmiumHashID::predates_checkpoint(before, checkpoint), Ok(true));
+    /// assert_eq!(WasmiumHashID::predates_checkpoint(at(checkpoint), checkpoint), Ok(false));
+    /// assert_eq!(WasmiumHashID::predates_checkpoint(after, checkpoint), Ok(false));
+    /// ```
+    pub fn predates_checkpoint(hash_id: [u8; ID_LEN], checkpoint: Tai64N) -> Result<bool, Error> {
+        WasmiumHashID::is_before(hash_id, checkpoint)
+    }
+
+    /// Check whether the TAI64N timestamps of two `WasmiumHashID`s are at most `window` apart.
+    /// Returns `false` if either timestamp is invalid
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let timestamp = Tai64N::now();
+    /// let first = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), timestamp);
+    /// let second = WasmiumHashID::new_with_timestamp(
+    ///     blake3::hash(b"bar"),
+    ///     timestamp + Duration::from_secs(5),
+    /// );
+    ///
+    /// assert!(WasmiumHashID::within_window(&first, &second, Duration::from_secs(60)));
+    /// assert!(WasmiumHashID::within_window(&second, &first, Duration::from_secs(60)));
+    /// assert!(!WasmiumHashID::within_window(&first, &second, Duration::from_secs(1)));
+    /// ```
+    pub fn within_window(a: &Self, b: &Self, window: Duration) -> bool {
+        match (a.tai64n(), b.tai64n()) {
+            (Ok(a), Ok(b)) => {
+                let difference = a.duration_since(&b).unwrap_or_else(|difference| difference);
+
+                difference <= window
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the index of the fixed width time bucket the TAI64N timestamp falls in,
+    /// `(timestamp - epoch) / bucket_width`, saturating at `u64::MAX`.
+    /// Errors if the timestamp is before `epoch` or `bucket_width` is zero
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::{Error, WasmiumHashID};
+    ///
+    /// let epoch = Tai64N::now();
+    /// let hour = Duration::from_secs(3600);
+    /// let hash_id = WasmiumHashID::new_with_timestamp(
+    ///     blake3::hash(b"foo"),
+    ///     epoch + Duration::from_secs(5 * 3600 + 42),
+    /// )
+    /// .build();
+    ///
+    /// assert_eq!(WasmiumHashID::bucket_index(hash_id, hour, epoch), Ok(5));
+    /// assert_eq!(
+    ///     WasmiumHashID::bucket_index(hash_id, hour, epoch + Duration::from_secs(6 * 3600)),
+    ///     Err(Error::BeforeEpoch)
+    /// );
+    /// assert_eq!(
+    ///     WasmiumHashID::bucket_index(hash_id, Duration::ZERO, epoch),
+    ///     Err(Error::ZeroDuration)
+    /// );
+    /// ```
+    pub fn bucket_index(
+        hash_id: [u8; ID_LEN],
+        bucket_width: Duration,
+        epoch: Tai64N,
+    ) -> Result<u64, Error> {
+        if bucket_width.is_zero() {
+            return Err(Error::ZeroDuration);
+        }
+
+        let timestamp = Tai64N::from_slice(&hash_id[..TIMESTAMP_LEN])?;
+        let elapsed = timestamp
+            .duration_since(&epoch)
+            .map_err(|_| Error::BeforeEpoch)?;
+
+        Ok(u64::try_from(elapsed.as_nanos() / bucket_width.as_nanos()).unwrap_or(u64::MAX))
+    }
+
+    /// Get the TAI64N timestamp truncated to second resolution as a `Tai64`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo")).build();
+    ///
+    /// let timestamp = WasmiumHashID::get_timestamp(hash_id).unwrap();
+    /// assert_eq!(WasmiumHashID::timestamp_tai64(hash_id), Ok(timestamp.0));
+    /// ```
+    pub fn timestamp_tai64(hash_id: [u8; ID_LEN]) -> Result<Tai64, Error> {
+        Ok(Tai64N::from_slice(&hash_id[..TIMESTAMP_LEN])?.into())
+    }
+
+    /// Check whether the TAI64N timestamp falls inside an inserted leap second, a `23:59:60` UTC.
+    ///
+    /// The label is read as true TAI as defined by TAI64, where label `2^62 + 10` is
+    /// `1970-01-01T00:00:00Z`, and compared against the leap seconds inserted up to 2017.
+    /// The conversions of the tai64 crate, such as `Tai64N::now` and `to_system_time`, apply a
+    /// fixed TAI - UTC offset of 37 seconds instead, and a `SystemTime` cannot hold a `23:59:60`,
+    /// so timestamps created from a `SystemTime` never describe a real leap second
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use tai64::{Tai64, Tai64N};
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let at = |label, nanos| {
+    ///     WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), Tai64N(Tai64(label), nanos)).build()
+    /// };
+    ///
+    /// // 2016-12-31T23:59:60Z, the 27th leap second
+    /// assert_eq!(WasmiumHashID::is_leap_second(at(4_611_686_019_910_616_740, 0)), Ok(true));
+    /// assert_eq!(WasmiumHashID::is_leap_second(at(4_611_686_019_910_616_740, 999_999_999)), Ok(true));
+    /// assert_eq!(WasmiumHashID::is_leap_second(at(4_611_686_019_910_616_739, 0)), Ok(false));
+    /// assert_eq!(WasmiumHashID::is_leap_second(at(4_611_686_019_910_616_741, 0)), Ok(false));
+    ///
+    /// // 1972-06-30T23:59:60Z, the first leap second
+    /// assert_eq!(WasmiumHashID::is_leap_second(at(4_611_686_018_506_184_714, 0)), Ok(true));
+    /// assert_eq!(WasmiumHashID::is_leap_second(at(4_611_686_018_506_184_715, 0)), Ok(false));
+    /// ```
+    pub fn is_leap_second(hash_id: [u8; ID_LEN]) -> Result<bool, Error> {
+        let Tai64N(Tai64(seconds), _) = Tai64N::from_slice(&hash_id[..TIMESTAMP_LEN])?;
+
+        Ok(LEAP_SECONDS
+            .iter()
+            .zip(0..)
+            .any(|(midnight, inserted_before)| {
+                TAI_UNIX_EPOCH + midnight + inserted_before == seconds
+            }))
+    }
+
+    /// Coarsen the TAI64N timestamp by rounding it down to a multiple of `granularity`
+    /// counted from the Unix epoch, keeping the Blake3 hash, so the exact creation time is hidden.
+    /// Timestamps that would round below TAI64 label 0 saturate at label 0.
+    /// The `WasmiumHashID` is returned unchanged if `granularity` is zero or the timestamp is invalid
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::{Tai64, Tai64N};
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hour = Duration::from_secs(3600);
+    /// let start = Tai64N::UNIX_EPOCH + hour * 450_000;
+    /// let first = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), start + Duration::new(10, 42));
+    /// let second = WasmiumHashID::new_with_timestamp(blake3::hash(b"bar"), start + Duration::from_secs(3000));
+    ///
+    /// let first = first.truncated_time(hour);
+    /// let second = second.truncated_time(hour);
+    /// assert_eq!(first.timestamp_bytes(), second.timestamp_bytes());
+    /// assert_eq!(WasmiumHashID::get_timestamp(first.build()), Ok(start));
+    /// assert_eq!(first.digest_bytes(), blake3::hash(b"foo").as_bytes());
+    ///
+    /// let before_epoch = WasmiumHashID::new_with_timestamp(
+    ///     blake3::hash(b"foo"),
+    ///     Tai64N::UNIX_EPOCH - Duration::from_secs(10),
+    /// );
+    /// assert_eq!(
+    ///     WasmiumHashID::get_timestamp(before_epoch.truncated_time(Duration::from_secs(1000)).build()),
+    ///     Ok(Tai64N::UNIX_EPOCH - Duration::from_secs(1000))
+    /// );
+    ///
+    /// let earliest = Tai64N(Tai64(0), 0);
+    /// let ancient = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), earliest + Duration::from_secs(1));
+    /// let truncated = ancient.truncated_time(Duration::from_secs(1000));
+    /// assert_eq!(WasmiumHashID::get_timestamp(truncated.build()), Ok(earliest));
+    /// ```
+    pub fn truncated_time(&self, granularity: Duration) -> WasmiumHashID {
+        let timestamp = match self.tai64n() {
+            Ok(timestamp) if !granularity.is_zero() => timestamp,
+            _ => return self.clone(),
+        };
+
+        let granularity = granularity.as_nanos();
+        let truncated = match timestamp.duration_since(&Tai64N::UNIX_EPOCH) {
+            Ok(elapsed) => {
+                let elapsed = elapsed.as_nanos();
+                Tai64N::UNIX_EPOCH + nanos_to_duration(elapsed - elapsed % granularity)
+            }
+            Err(remaining) => {
+                let remaining = remaining.as_nanos();
+                let rounded = remaining.div_ceil(granularity) * granularity;
+                let Tai64(unix_epoch) = Tai64::UNIX_EPOCH;
+
+                // Saturate at label 0 when rounding down would go below it
+                if rounded > unix_epoch as u128 * 1_000_000_000 {
+                    Tai64N(Tai64(0), 0)
+                } else {
+                    Tai64N::UNIX_EPOCH - nanos_to_duration(rounded)
+                }
+            }
+        };
+
+        let hash_id = WasmiumHashID {
+            tai_timestamp: truncated.to_bytes(),
+            blake3hash: self.blake3hash,
+        };
+        #[cfg(feature = "tracing")]
+        hash_id.trace("truncated_time");
+
+        hash_id
+    }
+
+    /// Generate a synthetic `WasmiumHashID` for test fixtures whose TAI64N timestamp is
+    /// linearly interpolated between the timestamps of `start` and `end`, with `t`
+    /// clamped to `[0, 1]` and `NaN` treated as 0. The Blake3 hash is derived from both
+    /// endpoint hashes and `t`, so the same inputs always give the same ID
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let timestamp = Tai64N::now();
+    /// let start = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), timestamp);
+    /// let end = WasmiumHashID::new_with_timestamp(
+    ///     blake3::hash(b"bar"),
+    ///     timestamp + Duration::from_secs(10),
+    /// );
+    ///
+    /// let first = WasmiumHashID::interpolate(&start, &end, 0.0);
+    /// let middle = WasmiumHashID::interpolate(&start, &end, 0.5);
+    /// let last = WasmiumHashID::interpolate(&start, &end, 1.0);
+    ///
+    /// assert_eq!(first.timestamp_bytes(), start.timestamp_bytes());
+    /// assert_eq!(last.timestamp_bytes(), end.timestamp_bytes());
+    /// assert_eq!(
+    ///     WasmiumHashID::get_timestamp(middle.build()),
+    ///     Ok(timestamp + Duration::from_secs(5))
+    /// );
+    /// assert_eq!(WasmiumHashID::interpolate(&start, &end, 2.0).timestamp_bytes(), end.timestamp_bytes());
+    /// assert_eq!(middle, WasmiumHashID::interpolate(&start, &end, 0.5));
+    /// assert_ne!(first.digest_bytes(), middle.digest_bytes());
+    /// ```
+    pub fn interpolate(start: &Self, end: &Self, t: f64) -> WasmiumHashID {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+
+        let from = total_nanos(&start.tai_timestamp);
+        let to = total_nanos(&end.tai_timestamp);
+        let nanos = from + ((to - from) as f64 * t).round() as i128;
+
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&start.blake3hash);
+        hasher.update(&end.blake3hash);
+        hasher.update(&t.to_bits().to_be_bytes());
+
+        WasmiumHashID::new_with_timestamp(
+            hasher.finalize(),
+            Tai64N(
+                Tai64((nanos / 1_000_000_000) as u64),
+                (nanos % 1_000_000_000) as u32,
+            ),
+        )
+    }
+}
+
+/// Get a raw TAI64N timestamp as the total number of nanoseconds since TAI64 label zero
+fn total_nanos(timestamp: &[u8; TIMESTAMP_LEN]) -> i128 {
+    let mut seconds = [0u8; 8];
+    seconds.copy_from_slice(&timestamp[..8]);
+    let mut nanos = [0u8; 4];
+    nanos.copy_from_slice(&timestamp[8..]);
+
+    u64::from_be_bytes(seconds) as i128 * 1_000_000_000 + u32::from_be_bytes(nanos) as i128
+}
+
+/// Convert a count of nanoseconds into a `Duration`
+fn nanos_to_duration(nanos: u128) -> Duration {
+    Duration::new(
+        (nanos / 1_000_000_000) as u64,
+        (nanos % 1_000_000_000) as u32,
+    )
+}
@@ -0,0 +1,114 @@
+use std::io;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use tai64::Tai64N;
+
+use crate::{Error, WasmiumHashID, TIMESTAMP_LEN};
+
+/// The 12 byte TAI64N timestamp of a `WasmiumHashID` on its own, for schemas that only store the time.
+/// Unlike `WasmiumHashID`, deserializing with borsh validates the timestamp
+///
+/// #### Usage
+/// ```rust
+/// use borsh::{BorshDeserialize, BorshSerialize};
+/// use wasmium_hash_id::{HashIDTimestamp, WasmiumHashID};
+///
+/// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+/// let bytes = hash_id.timestamp_to_borsh();
+///
+/// let timestamp = HashIDTimestamp::try_from_slice(&bytes).unwrap();
+/// assert_eq!(Ok(timestamp.to_tai64n()), WasmiumHashID::get_timestamp(hash_id.build()));
+/// assert_eq!(timestamp.try_to_vec().unwrap(), bytes);
+///
+/// let mut invalid = bytes.clone();
+/// invalid[8..].copy_from_slice(&u32::MAX.to_be_bytes());
+/// assert!(HashIDTimestamp::try_from_slice(&invalid).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, BorshSerialize)]
+pub struct HashIDTimestamp([u8; TIMESTAMP_LEN]);
+
+impl HashIDTimestamp {
+    /// Create a `HashIDTimestamp` from its 12 bytes, validating the TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::HashIDTimestamp;
+    ///
+    /// let now = Tai64N::now();
+    /// let timestamp = HashIDTimestamp::new(now.to_bytes()).unwrap();
+    ///
+    /// assert_eq!(timestamp.to_tai64n(), now);
+    /// assert!(HashIDTimestamp::new([0xff; 12]).is_err());
+    /// ```
+    pub fn new(bytes: [u8; TIMESTAMP_LEN]) -> Result<Self, Error> {
+        Tai64N::from_slice(&bytes)?;
+
+        Ok(HashIDTimestamp(bytes))
+    }
+
+    /// Get the 12 bytes of the TAI64N timestamp
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::HashIDTimestamp;
+    ///
+    /// let now = Tai64N::now();
+    /// let timestamp = HashIDTimestamp::from(now);
+    ///
+    /// assert_eq!(timestamp.as_bytes(), &now.to_bytes());
+    /// ```
+    pub fn as_bytes(&self) -> &[u8; TIMESTAMP_LEN] {
+        &self.0
+    }
+
+    /// Convert to a `Tai64N`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use tai64::Tai64N;
+    /// use wasmium_hash_id::HashIDTimestamp;
+    ///
+    /// let now = Tai64N::now();
+    ///
+    /// assert_eq!(HashIDTimestamp::from(now).to_tai64n(), now);
+    /// ```
+    pub fn to_tai64n(&self) -> Tai64N {
+        Tai64N::from_slice(&self.0).expect("A `HashIDTimestamp` always holds a valid timestamp")
+    }
+}
+
+impl From<Tai64N> for HashIDTimestamp {
+    fn from(timestamp: Tai64N) -> Self {
+        HashIDTimestamp(timestamp.to_bytes())
+    }
+}
+
+impl BorshDeserialize for HashIDTimestamp {
+    fn deserialize(buf: &mut &[u8]) -> io::Result<Self> {
+        let bytes = <[u8; TIMESTAMP_LEN]>::deserialize(buf)?;
+
+        HashIDTimestamp::new(bytes)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error.to_string()))
+    }
+}
+
+impl WasmiumHashID {
+    /// Serialize only the TAI64N timestamp of the `WasmiumHashID` with borsh,
+    /// read it back with `HashIDTimestamp::try_from_slice`
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let hash_id = WasmiumHashID::new(blake3::hash(b"foo"));
+    ///
+    /// assert_eq!(hash_id.timestamp_to_borsh(), hash_id.build()[..12]);
+    /// ```
+    pub fn timestamp_to_borsh(&self) -> Vec<u8> {
+        self.tai_timestamp
+            .try_to_vec()
+            .expect("Writing into a `Vec` never fails")
+    }
+}
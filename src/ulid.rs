@@ -0,0 +1,55 @@
+use crate::WasmiumHashID;
+
+/// The Crockford base32 alphabet used by ULIDs
+const CROCKFORD_CHARS: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The number of characters in a ULID
+const ULID_LEN: usize = 26;
+
+impl WasmiumHashID {
+    /// Encode the `WasmiumHashID` as a 26 character ULID compatible string: 48 bits of
+    /// milliseconds since the Unix epoch, saturating at `2^48 - 1`, followed by the first 80 bits
+    /// of the Blake3 hash, in Crockford base32. The nanoseconds below a millisecond and the remaining 176 bits
+    /// of the hash are dropped, so the conversion cannot be reversed
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::{Tai64, Tai64N};
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let start = Tai64N::now();
+    /// let ulids = [0u64, 1, 60, 3600]
+    ///     .iter()
+    ///     .map(|seconds| {
+    ///         WasmiumHashID::new_with_timestamp(
+    ///             blake3::hash(&seconds.to_le_bytes()),
+    ///             start + Duration::from_secs(*seconds),
+    ///         )
+    ///         .to_ulid_like()
+    ///     })
+    ///     .collect::<Vec<String>>();
+    ///
+    /// assert!(ulids.iter().all(|ulid| ulid.len() == 26));
+    /// assert!(ulids.windows(2).all(|pair| pair[0][..10] < pair[1][..10]));
+    ///
+    /// let same_time = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), start).to_ulid_like();
+    /// assert_eq!(same_time[..10], ulids[0][..10]);
+    ///
+    /// let far_future = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), Tai64N(Tai64(u64::MAX), 0));
+    /// assert_eq!(&far_future.to_ulid_like()[..10], "7ZZZZZZZZZ");
+    /// ```
+    pub fn to_ulid_like(&self) -> String {
+        let mut bytes = [0u8; 16];
+        bytes[..6].copy_from_slice(&self.unix_millis48().to_be_bytes()[2..]);
+        bytes[6..].copy_from_slice(&self.blake3hash[..10]);
+        let value = u128::from_be_bytes(bytes);
+
+        (0..ULID_LEN)
+            .map(|index| {
+                let shift = 5 * (ULID_LEN - 1 - index);
+                CROCKFORD_CHARS[((value >> shift) & 0x1f) as usize] as char
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,47 @@
+use uuid::{Builder, Uuid};
+
+use crate::WasmiumHashID;
+
+impl WasmiumHashID {
+    /// Pack the `WasmiumHashID` into a UUIDv8 that keeps creation time ordering.
+    ///
+    /// The first 48 bits hold the milliseconds since the Unix epoch, like a UUIDv7, saturating
+    /// at `2^48 - 1`. They are followed by the 4 version bits, 12 zero bits, the 2 variant bits
+    /// and the first 62 bits of the Blake3 hash. The conversion is lossy and cannot be reversed
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tai64::{Tai64, Tai64N};
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let timestamp = Tai64N::UNIX_EPOCH + Duration::from_secs(1_704_164_645);
+    /// let first = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), timestamp);
+    /// let second = WasmiumHashID::new_with_timestamp(
+    ///     blake3::hash(b"bar"),
+    ///     timestamp + Duration::from_millis(1),
+    /// );
+    ///
+    /// let uuid = first.to_uuid_v8();
+    /// assert_eq!(uuid.get_version_num(), 8);
+    /// assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+    /// assert!(uuid < second.to_uuid_v8());
+    ///
+    /// let value = uuid.as_u128();
+    /// assert_eq!((value >> 80) as u64, 1_704_164_645_000);
+    /// assert_eq!((value >> 64) & 0x0fff, 0);
+    /// let mut digest = [0u8; 8];
+    /// digest.copy_from_slice(&first.digest_bytes()[..8]);
+    /// assert_eq!(value as u64 & (u64::MAX >> 2), u64::from_be_bytes(digest) >> 2);
+    ///
+    /// let far_future = WasmiumHashID::new_with_timestamp(blake3::hash(b"foo"), Tai64N(Tai64(u64::MAX), 0));
+    /// assert_eq!((far_future.to_uuid_v8().as_u128() >> 80) as u64, (1 << 48) - 1);
+    /// ```
+    pub fn to_uuid_v8(&self) -> Uuid {
+        let mut bytes = [0u8; 16];
+        bytes[..6].copy_from_slice(&self.unix_millis48().to_be_bytes()[2..]);
+        bytes[8..].copy_from_slice(&(self.digest_u64(0) >> 2).to_be_bytes());
+
+        Builder::from_custom_bytes(bytes).into_uuid()
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{WasmiumHashID, HASH_LEN};
+
+impl WasmiumHashID {
+    /// Check whether the Blake3 hash starts with at least `leading_zero_bits` zero bits,
+    /// a lightweight proof of work gate. Difficulties above 256 are never met
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let mut digest = [0xffu8; 32];
+    /// digest[0] = 0x00;
+    /// digest[1] = 0x1f;
+    /// let hash_id = WasmiumHashID::new(digest.into());
+    ///
+    /// assert!(hash_id.meets_difficulty(0));
+    /// assert!(hash_id.meets_difficulty(11));
+    /// assert!(!hash_id.meets_difficulty(12));
+    /// assert!(WasmiumHashID::new([0u8; 32].into()).meets_difficulty(256));
+    /// assert!(!WasmiumHashID::new([0u8; 32].into()).meets_difficulty(257));
+    /// ```
+    pub fn meets_difficulty(&self, leading_zero_bits: u32) -> bool {
+        leading_zeros(&self.blake3hash) >= leading_zero_bits
+    }
+
+    /// Search for the first nonce whose Blake3 hash of `message || nonce`, with the nonce as big
+    /// endian bytes, meets `difficulty`, and return the `WasmiumHashID` stamped with the current
+    /// TAI64N timestamp together with the nonce. Every extra bit doubles the expected work.
+    /// Panics straight away if `difficulty` is above 256, since no Blake3 hash can meet it
+    ///
+    /// #### Usage
+    /// ```rust
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// let (hash_id, nonce) = WasmiumHashID::mine(b"foo", 8);
+    /// assert!(hash_id.meets_difficulty(8));
+    ///
+    /// let mut preimage = b"foo".to_vec();
+    /// preimage.extend_from_slice(&nonce.to_be_bytes());
+    /// assert_eq!(hash_id.digest_bytes(), blake3::hash(&preimage).as_bytes());
+    /// ```
+    ///
+    /// An impossible difficulty panics without searching
+    /// ```rust,should_panic
+    /// use wasmium_hash_id::WasmiumHashID;
+    ///
+    /// WasmiumHashID::mine(b"foo", 257);
+    /// ```
+    pub fn mine(message: &[u8], difficulty: u32) -> (WasmiumHashID, u64) {
+        assert!(
+            difficulty <= HASH_LEN as u32 * 8,
+            "difficulty {} exceeds the {} bits of a Blake3 hash",
+            difficulty,
+            HASH_LEN * 8
+        );
+
+        let mut prefix = blake3::Hasher::new();
+        prefix.update(message);
+
+        (0..=u64::MAX)
+            .map(|nonce| {
+                let mut hasher = prefix.clone();
+                hasher.update(&nonce.to_be_bytes());
+                (hasher.finalize(), nonce)
+            })
+            .find(|(hash, _)| leading_zeros(hash.as_bytes()) >= difficulty)
+            .map(|(hash, nonce)| (WasmiumHashID::new(hash), nonce))
+            .expect("no nonce meets the difficulty")
+    }
+}
+
+/// Count the leading zero bits of a Blake3 hash
+fn leading_zeros(digest: &[u8; HASH_LEN]) -> u32 {
+    let mut zeros = 0;
+    for byte in digest {
+        zeros += byte.leading_zeros();
+        if *byte != 0 {
+            break;
+        }
+    }
+
+    zeros
+}